@@ -1,208 +1,242 @@
+use crate::error::Error;
 use std::{
+    borrow::Cow,
+    collections::HashMap,
     ffi::OsStr,
-    io::{self, Write},
-    process::Command,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
 };
 
-/// Executes a command with specified arguments in the given directory.
-///
-/// This function executes a command with the provided name and arguments in the specified directory (`path`).
-///
-/// # Arguments
-///
-/// * `name` - The name of the command to execute.
-/// * `path` - The directory in which to execute the command.
-/// * `args` - A slice of string arguments to pass to the command.
-/// * `print_status` - A boolean flag indicating whether to print the command's exit status and output.
-///
-/// # Example
-///
-/// ```rust
-/// use rca::command::execute_command;
-/// use std::ffi::OsStr;
-///
-/// let command_name = "cargo";
-/// let command_args = &["build"];
-/// let directory = OsStr::new("./");
-///
-/// execute_command(command_name, directory, command_args, true);
-/// ```
-pub fn execute_command(name: &str, path: &OsStr, args: &[&str], print_status: bool) {
-    let output = Command::new(name)
-        .current_dir(path)
-        .args(args)
-        .output()
-        .expect("failed to execute process");
-
-    match print_status {
-        true => println!("Status: {}", output.status),
-        false => (),
+/// The captured result of a [`Cmd`] run via [`Cmd::capture`].
+///
+/// Unlike [`std::process::Output`], `stdout`/`stderr` are already decoded as `String`
+/// (via `String::from_utf8_lossy`) so callers never have to deal with raw bytes or risk
+/// corrupting non-ASCII diagnostic output.
+#[derive(Debug)]
+pub struct Captured {
+    /// The exit status of the finished process.
+    pub status: ExitStatus,
+    /// The process's captured, lossily-decoded standard output.
+    pub stdout: String,
+    /// The process's captured, lossily-decoded standard error.
+    pub stderr: String,
+}
+
+impl Captured {
+    /// Returns `true` if the process exited successfully.
+    pub fn success(&self) -> bool {
+        self.status.success()
     }
+}
 
-    io::stdout().write_all(&output.stdout).unwrap();
-    io::stderr().write_all(&output.stderr).unwrap();
+/// Resolves `name` to the executable name the current platform expects, mirroring the
+/// rust-analyzer xtask `Cmd` pattern of picking a `unix` vs `windows` command string. On
+/// Windows this appends `.exe` (e.g. `cargo` -> `cargo.exe`); everywhere else `name` is
+/// returned unchanged.
+fn platform_executable(name: &str) -> Cow<'_, str> {
+    if cfg!(windows) && !name.ends_with(".exe") {
+        Cow::Owned(format!("{name}.exe"))
+    } else {
+        Cow::Borrowed(name)
+    }
 }
 
-/// Executes a command with specified arguments without specifying a directory.
-///
-/// This function executes a command with the provided name and arguments in the current working directory.
-///
-/// # Arguments
-///
-/// * `name` - The name of the command to execute.
-/// * `args` - A slice of string arguments to pass to the command.
-/// * `print_status` - A boolean flag indicating whether to print the command's exit status and output.
-///
-/// # Returns
-///
-/// `Ok(())` if the command execution is successful, or `Err(io::Error)` if there is an error during execution.
-///
-/// # Example
-///
-/// ```rust
-/// use rca::command::execute_command_no_path;
-///
-/// let command_name = "echo";
-/// let command_args = &["hello world"];
-///
-/// match execute_command_no_path(command_name, command_args, true) {
-///     Ok(()) => println!("Command executed successfully."),
-///     Err(error) => eprintln!("Error executing command: {}", error),
-/// }
-/// ```
-pub fn execute_command_no_path(
-    name: &str,
-    args: &[&str],
-    print_status: bool,
-) -> Result<(), io::Error> {
-    let output: Result<std::process::Output, io::Error> = Command::new(name).args(args).output();
-
-    match output {
-        Err(error) => return Err(error),
-        Ok(output) => {
-            match print_status {
-                true => println!("Status: {}", output.status),
-                false => (),
-            }
-
-            io::stdout().write_all(&output.stdout).unwrap();
-            io::stderr().write_all(&output.stderr).unwrap();
+/// Checks whether `name` is installed and resolvable on `PATH`, accounting for the
+/// current platform's executable naming (see [`platform_executable`]).
+pub fn is_installed(name: &str) -> bool {
+    which::which(platform_executable(name).as_ref()).is_ok()
+}
 
-            Ok(())
-        }
+/// The command a user would run to install `tool`, if this crate knows one.
+///
+/// Covers the external `cargo` subcommands and `rustup` components this crate shells out to;
+/// tools built into `cargo` itself (`check`, `build`, `tree`, ...) need no install hint since
+/// they can never be the thing that's missing.
+pub fn install_hint(tool: &str) -> Option<&'static str> {
+    match tool {
+        "tokei" => Some("cargo install tokei"),
+        "cargo-tarpaulin" => Some("cargo install cargo-tarpaulin"),
+        "cargo-audit" => Some("cargo install cargo-audit"),
+        "cargo-outdated" => Some("cargo install cargo-outdated"),
+        "cargo-clippy" => Some("rustup component add clippy"),
+        "cargo-fmt" => Some("rustup component add rustfmt"),
+        _ => None,
     }
 }
 
-/// Executes a command with specified arguments in a given directory and returns the command's output.
-///
-/// This function takes the name of the command, the directory path, a list of arguments,
-/// and a boolean flag to determine whether to print the command's exit status.
-///
-/// # Arguments
-///
-/// * `name` - The name of the command to execute.
-/// * `path` - The directory path in which to execute the command.
-/// * `args` - A slice of string arguments to pass to the command.
-/// * `print_status` - A boolean flag indicating whether to print the command's exit status.
-///
-/// # Returns
-///
-/// Returns a `Result` containing either the command's output as a `String` on success or an `io::Error` on failure.
+/// A builder for running external commands (`cargo`, `rustup`, `tokei`, ...).
+///
+/// `Cmd` replaces the crate's previous family of near-identical
+/// `execute_command`/`execute_command_no_path`/`execute_command_return`/`execute_command_no_path_return`
+/// functions, which differed only in whether a working directory was set and whether output was
+/// captured or streamed. This mirrors the rust-analyzer xtask `Cmd` type: `run` inherits the
+/// parent's stdout/stderr so long-running tools like `cargo audit` or `cargo clippy` stream their
+/// output live, while `capture` collects it for programmatic inspection. Both resolve the
+/// program name per-platform and check it is on `PATH` before spawning (see
+/// [`is_installed`]), so a missing tool surfaces as [`Error::MissingTool`] instead of a raw
+/// OS "file not found" error.
 ///
 /// # Example
 ///
-/// ```
+/// ```rust
+/// use rca::command::Cmd;
 /// use std::ffi::OsStr;
-/// use std::io;
-/// use rca::command::execute_command_return;
 ///
-/// let command_name = "ls";
-/// let path = OsStr::new(".");
-/// let args = &[];
-/// let print_status = true;
+/// let output = Cmd::new("cargo")
+///     .current_dir(OsStr::new("./"))
+///     .args(["--version"])
+///     .capture();
 ///
-/// match execute_command_return(command_name, path, args, print_status) {
-///     Ok(output) => {
-///         println!("Command Output: {}", output);
-///     }
-///     Err(error) => {
-///         eprintln!("Command Error: {}", error);
-///     }
-/// }
+/// assert!(output.is_ok());
 /// ```
-///
-pub fn execute_command_return(
-    name: &str,
-    path: &OsStr,
-    args: &[&str],
-    print_status: bool,
-) -> Result<String, io::Error> {
-    let output = Command::new(name).current_dir(path).args(args).output();
-
-    match output {
-        Err(error) => return Err(error),
-        Ok(output) => {
-            match print_status {
-                true => println!("Status: {}", output.status),
-                false => (),
-            }
-
-            return Ok(output.stdout.iter().map(|&i| i as char).collect::<String>());
+pub struct Cmd {
+    name: String,
+    args: Vec<String>,
+    current_dir: Option<PathBuf>,
+    env: HashMap<String, String>,
+    requires: Option<String>,
+}
+
+impl Cmd {
+    /// Creates a new `Cmd` for the given program name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            args: Vec::new(),
+            current_dir: None,
+            env: HashMap::new(),
+            requires: None,
         }
     }
-}
 
-/// Executes a command with specified arguments and returns the command's output.
-///
-/// This function takes the name of the command, a list of arguments,
-/// and a boolean flag to determine whether to print the command's exit status.
-///
-/// # Arguments
-///
-/// * `name` - The name of the command to execute.
-/// * `args` - A slice of string arguments to pass to the command.
-/// * `print_status` - A boolean flag indicating whether to print the command's exit status.
-///
-/// # Returns
-///
-/// Returns a `Result` containing either the command's output as a `String` on success or an `io::Error` on failure.
-///
-/// # Example
-///
-/// ```
-/// use std::io;
-/// use rca::command::execute_command_no_path_return;
-///
-/// let command_name = "echo";
-/// let args = &["Hello World"];
-/// let print_status = true;
-///
-/// match execute_command_no_path_return(command_name, args, print_status) {
-///     Ok(output) => {
-///         println!("Command Output: {}", output);
-///     }
-///     Err(error) => {
-///         eprintln!("Command Error: {}", error);
-///     }
-/// }
-/// ```
-///
-pub fn execute_command_no_path_return(
-    name: &str,
-    args: &[&str],
-    print_status: bool,
-) -> Result<String, io::Error> {
-    let output: Result<std::process::Output, io::Error> = Command::new(name).args(args).output();
-
-    match output {
-        Err(error) => return Err(error),
-        Ok(output) => {
-            match print_status {
-                true => println!("Status: {}", output.status),
-                false => (),
-            }
-
-            return Ok(output.stdout.iter().map(|&i| i as char).collect::<String>());
+    /// Appends a single argument.
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Appends multiple arguments.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the working directory the command runs in. If unset, the current
+    /// process's working directory is used.
+    pub fn current_dir(mut self, path: impl AsRef<OsStr>) -> Self {
+        self.current_dir = Some(Path::new(&path).to_path_buf());
+        self
+    }
+
+    /// Sets an environment variable for the spawned process.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Overrides the binary whose presence on `PATH` is preflight-checked before running.
+    ///
+    /// Needed for `cargo` subcommands that ship as a separate binary (e.g. `cargo tarpaulin`
+    /// is really `cargo-tarpaulin` on `PATH`): checking `cargo` itself would never catch a
+    /// missing subcommand, since `cargo` is always installed. Leave unset for plain binaries
+    /// and subcommands built into `cargo` itself (`check`, `build`, `tree`, ...).
+    pub fn requires(mut self, tool: impl Into<String>) -> Self {
+        self.requires = Some(tool.into());
+        self
+    }
+
+    /// The binary this command's preflight check verifies is on `PATH`: `requires` if set,
+    /// otherwise the program name itself.
+    fn required_tool(&self) -> &str {
+        self.requires.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Builds the [`Error::MissingTool`] for `tool`, filling in its install hint if known.
+    fn missing_tool(tool: &str) -> Error {
+        Error::MissingTool {
+            tool: tool.to_string(),
+            install_hint: install_hint(tool).map(str::to_string),
+        }
+    }
+
+    fn build(&self) -> Command {
+        let mut command = Command::new(platform_executable(&self.name).as_ref());
+        command.args(&self.args);
+
+        if let Some(path) = &self.current_dir {
+            command.current_dir(path);
         }
+
+        for (key, value) in &self.env {
+            command.env(key, value);
+        }
+
+        command
+    }
+
+    /// Runs the command, inheriting the parent's stdout/stderr so output streams live.
+    ///
+    /// Use this for long-running tools (`cargo audit`, `cargo clippy`, ...) where the user
+    /// should see progress as it happens rather than waiting for the process to finish.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingTool`] if `name` is not on `PATH`, [`Error::Spawn`] if the
+    /// process could not be started for some other reason, or [`Error::NonZeroExit`] if it
+    /// ran but exited with a failure status.
+    pub fn run(&self) -> Result<(), Error> {
+        if !is_installed(self.required_tool()) {
+            return Err(Self::missing_tool(self.required_tool()));
+        }
+
+        let status = self
+            .build()
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .map_err(|source| Error::Spawn {
+                command: self.name.clone(),
+                source,
+            })?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::NonZeroExit {
+                command: self.name.clone(),
+                status,
+            })
+        }
+    }
+
+    /// Runs the command and captures its stdout/stderr instead of streaming them.
+    ///
+    /// Unlike [`Cmd::run`], a non-zero exit status is not treated as an error here: callers
+    /// that need to inspect output regardless of success can check [`Captured::success`]
+    /// themselves.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingTool`] if `name` is not on `PATH`, or [`Error::Spawn`] if the
+    /// process could not be started for some other reason.
+    pub fn capture(&self) -> Result<Captured, Error> {
+        if !is_installed(self.required_tool()) {
+            return Err(Self::missing_tool(self.required_tool()));
+        }
+
+        let output = self.build().output().map_err(|source| Error::Spawn {
+            command: self.name.clone(),
+            source,
+        })?;
+
+        Ok(Captured {
+            status: output.status,
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
     }
 }
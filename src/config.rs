@@ -0,0 +1,126 @@
+use crate::{error::Error, issues, quality};
+use serde::Deserialize;
+use std::{ffi::OsStr, fs, path::Path};
+
+/// Declares which checks `rca` should run in non-interactive (CI) mode, and which of those
+/// should be treated as hard failures.
+///
+/// A `Config` can come from a `rca.toml` file (see [`Config::from_file`]) or from a
+/// `--checks a,b,c` command-line flag (see [`Config::from_checks_flag`]), mirroring the
+/// `config.rs` pattern used by rustc_codegen_gcc's build system.
+///
+/// # Example
+///
+/// ```
+/// use rca::config::Config;
+///
+/// let config = Config::from_checks_flag("fmt,audit,arithmetic,sloc");
+/// assert_eq!(config.checks, vec!["fmt", "audit", "arithmetic", "sloc"]);
+/// assert!(config.blocking.contains(&"fmt".to_string()));
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    /// Names of the checks to run. See [`run_named_check`] for the list of valid names.
+    #[serde(default)]
+    pub checks: Vec<String>,
+    /// The subset of `checks` whose failure should cause a non-zero exit status.
+    #[serde(default)]
+    pub blocking: Vec<String>,
+    /// Whether to run [`crate::dependencies::update_and_install_dependencies`] before checking.
+    #[serde(default)]
+    pub install_dependencies: bool,
+    /// The interchange format the `coverage` check exports its report in: `json` (the
+    /// default), `lcov`, `cobertura`, `html`, or `sonar`. See [`crate::coverage::reporter_for`].
+    #[serde(default = "default_coverage_format")]
+    pub coverage_format: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            checks: Vec::new(),
+            blocking: Vec::new(),
+            install_dependencies: false,
+            coverage_format: default_coverage_format(),
+        }
+    }
+}
+
+fn default_coverage_format() -> String {
+    "json".to_string()
+}
+
+impl Config {
+    /// Loads a `Config` from a TOML file (e.g. `rca.toml`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain valid TOML.
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Builds a `Config` from a comma-separated `--checks` flag value, treating every named
+    /// check as blocking.
+    pub fn from_checks_flag(checks: &str) -> Self {
+        let checks: Vec<String> = checks
+            .split(',')
+            .map(str::trim)
+            .filter(|check| !check.is_empty())
+            .map(String::from)
+            .collect();
+
+        Self {
+            blocking: checks.clone(),
+            checks,
+            ..Default::default()
+        }
+    }
+}
+
+/// Runs a single named check against `path`.
+///
+/// `coverage_format` selects the [`crate::coverage::CoverageReporter`] the `coverage` check
+/// exports its report through; it is ignored by every other check.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownCheck`] if `name` does not match any known check, or whatever error
+/// the underlying `issues`/`quality` function returns.
+pub fn run_named_check(name: &str, path: &OsStr, coverage_format: &str) -> Result<(), Error> {
+    match name {
+        "check" => issues::find_compilation_errors(path),
+        "fmt" => issues::find_formatting_issues(path),
+        "outdated" => issues::find_outdated_dependencies(path),
+        "audit" => issues::find_vulnerable_dependencies(path),
+        "arithmetic" => issues::find_integer_arithmetics(path),
+        "unwrap" => issues::find_unwrap_expect(path),
+        "sloc" => quality::search_sloc_number(path),
+        "tree" => quality::search_dependency_graph(path),
+        "coverage" => quality::export_coverage(path, coverage_format),
+        _ => Err(Error::UnknownCheck(name.to_string())),
+    }
+}
+
+/// Runs every check in `config.checks` against `path`, printing a failure for each.
+///
+/// # Returns
+///
+/// `true` if any check listed in `config.blocking` failed, `false` otherwise. This lets the
+/// caller decide the process's exit status without treating every failing check as fatal.
+pub fn run_checks(config: &Config, path: &OsStr) -> bool {
+    let mut blocking_failed = false;
+
+    for name in &config.checks {
+        if let Err(error) = run_named_check(name, path, &config.coverage_format) {
+            eprintln!("[{}] {}", name, error);
+
+            if config.blocking.iter().any(|blocking| blocking == name) {
+                blocking_failed = true;
+            }
+        }
+    }
+
+    blocking_failed
+}
@@ -0,0 +1,386 @@
+use crate::report::{Coverage, CoverageStat, FileCoverage};
+use std::collections::HashMap;
+use std::fs;
+
+/// Exports a [`Coverage`] report to disk in some interchange format that downstream CI systems
+/// (Coveralls, Codecov, SonarQube, GitLab, ...) understand natively, as an alternative to the
+/// crate's own `quality_report.json` shape.
+pub trait CoverageReporter {
+    /// Writes `coverage` to disk using `name` as the file stem (an extension appropriate to the
+    /// format is appended), returning the path written to.
+    fn write(
+        &self,
+        coverage: &Coverage,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>>;
+}
+
+/// Looks up the [`CoverageReporter`] for a `--format` value (`json`, `lcov`, `cobertura`,
+/// `html`, or `sonar`).
+///
+/// # Returns
+///
+/// `None` if `format` does not match any known reporter.
+pub fn reporter_for(format: &str) -> Option<Box<dyn CoverageReporter>> {
+    match format {
+        "json" => Some(Box::new(JsonReporter)),
+        "lcov" => Some(Box::new(LcovReporter)),
+        "cobertura" => Some(Box::new(CoberturaReporter)),
+        "html" => Some(Box::new(HtmlReporter)),
+        "sonar" => Some(Box::new(SonarCoverageReporter)),
+        _ => None,
+    }
+}
+
+/// Writes the report as pretty-printed JSON, the crate's native `Coverage` shape.
+pub struct JsonReporter;
+
+impl CoverageReporter for JsonReporter {
+    fn write(
+        &self,
+        coverage: &Coverage,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let content = serde_json::to_string_pretty(coverage)?;
+        let path = format!("{name}.json");
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+}
+
+/// Writes the report as an LCOV `.info` trace file, understood by `genhtml`, Coveralls, and
+/// Codecov.
+pub struct LcovReporter;
+
+impl CoverageReporter for LcovReporter {
+    fn write(
+        &self,
+        coverage: &Coverage,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut lcov = String::new();
+
+        for file in &coverage.file_coverage {
+            lcov.push_str(&format!("SF:{}\n", file.name));
+
+            let mut lines_found = 0;
+            let mut lines_hit = 0;
+            let mut branches_found = 0;
+            let mut branches_hit = 0;
+
+            for trace in &file.traces {
+                match &trace.stats {
+                    CoverageStat::Line(hits) => {
+                        lcov.push_str(&format!("DA:{},{}\n", trace.line, hits));
+                        lines_found += 1;
+                        if *hits > 0 {
+                            lines_hit += 1;
+                        }
+                    }
+                    CoverageStat::Branch(branch) => {
+                        for (block, taken) in [branch.been_true, branch.been_false]
+                            .into_iter()
+                            .enumerate()
+                        {
+                            lcov.push_str(&format!(
+                                "BRDA:{},0,{},{}\n",
+                                trace.line,
+                                block,
+                                u8::from(taken)
+                            ));
+                            branches_found += 1;
+                            branches_hit += u32::from(taken);
+                        }
+                    }
+                    CoverageStat::Condition(conditions) => {
+                        for (index, condition) in conditions.iter().enumerate() {
+                            for (block, taken) in
+                                [condition.been_true, condition.been_false].into_iter().enumerate()
+                            {
+                                lcov.push_str(&format!(
+                                    "BRDA:{},0,{},{}\n",
+                                    trace.line,
+                                    index * 2 + block,
+                                    u8::from(taken)
+                                ));
+                                branches_found += 1;
+                                branches_hit += u32::from(taken);
+                            }
+                        }
+                    }
+                }
+            }
+
+            lcov.push_str(&format!("LF:{lines_found}\n"));
+            lcov.push_str(&format!("LH:{lines_hit}\n"));
+            lcov.push_str(&format!("BRF:{branches_found}\n"));
+            lcov.push_str(&format!("BRH:{branches_hit}\n"));
+            lcov.push_str("end_of_record\n");
+        }
+
+        let path = format!("{name}.info");
+        fs::write(&path, lcov)?;
+        Ok(path)
+    }
+}
+
+/// Writes the report as a Cobertura-compatible XML document, understood by SonarQube, Jenkins,
+/// and GitLab.
+pub struct CoberturaReporter;
+
+impl CoverageReporter for CoberturaReporter {
+    fn write(
+        &self,
+        coverage: &Coverage,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let line_rate = rate(coverage.num_covered_lines, coverage.total_lines);
+        let branch_rate = rate(
+            coverage.num_covered_branches + coverage.num_covered_conditions,
+            coverage.total_branches + coverage.total_conditions,
+        );
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\"?>\n");
+        xml.push_str(&format!(
+            "<coverage line-rate=\"{line_rate:.4}\" branch-rate=\"{branch_rate:.4}\" lines-covered=\"{}\" lines-valid=\"{}\">\n",
+            coverage.num_covered_lines, coverage.total_lines
+        ));
+        xml.push_str("  <packages>\n    <package name=\"rca\">\n      <classes>\n");
+
+        for file in &coverage.file_coverage {
+            xml.push_str(&format!(
+                "        <class name=\"{0}\" filename=\"{0}\">\n          <lines>\n",
+                file.name
+            ));
+
+            for trace in &file.traces {
+                match &trace.stats {
+                    CoverageStat::Line(hits) => {
+                        xml.push_str(&format!(
+                            "            <line number=\"{}\" hits=\"{hits}\" branch=\"false\"/>\n",
+                            trace.line
+                        ));
+                    }
+                    CoverageStat::Branch(branch) => {
+                        let hits = u8::from(branch.been_true) + u8::from(branch.been_false);
+                        xml.push_str(&format!(
+                            "            <line number=\"{}\" hits=\"{hits}\" branch=\"true\" condition-coverage=\"{:.0}%\"/>\n",
+                            trace.line,
+                            rate(u32::from(branch.is_covered()), 1) * 100.0
+                        ));
+                    }
+                    CoverageStat::Condition(conditions) => {
+                        let covered = conditions.iter().filter(|c| c.is_covered()).count() as u32;
+                        xml.push_str(&format!(
+                            "            <line number=\"{}\" hits=\"1\" branch=\"true\" condition-coverage=\"{:.0}%\"/>\n",
+                            trace.line,
+                            rate(covered, conditions.len() as u32) * 100.0
+                        ));
+                    }
+                }
+            }
+
+            xml.push_str("          </lines>\n        </class>\n");
+        }
+
+        xml.push_str("      </classes>\n    </package>\n  </packages>\n</coverage>\n");
+
+        let path = format!("{name}.xml");
+        fs::write(&path, xml)?;
+        Ok(path)
+    }
+}
+
+/// Writes a single self-contained HTML document with a project-wide summary header followed by
+/// a per-file, line-by-line rendering of `content` with uncovered lines and partially-covered
+/// branches/conditions highlighted, so the report can be browsed without cross-referencing line
+/// numbers against an editor.
+pub struct HtmlReporter;
+
+impl CoverageReporter for HtmlReporter {
+    fn write(
+        &self,
+        coverage: &Coverage,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str("<title>Coverage Report</title>\n<style>\n");
+        html.push_str(
+            "body { font-family: sans-serif; }\n\
+             table { border-collapse: collapse; font-family: monospace; font-size: 13px; }\n\
+             .gutter { color: #888; text-align: right; padding-right: 8px; user-select: none; }\n\
+             .covered { background-color: #d9f2d9; }\n\
+             .partial { background-color: #fcf3cf; }\n\
+             .uncovered { background-color: #f8d7da; }\n\
+             pre { margin: 0; }\n",
+        );
+        html.push_str("</style>\n</head>\n<body>\n");
+
+        html.push_str("<h1>Coverage Summary</h1>\n<ul>\n");
+        html.push_str(&format!(
+            "<li>Lines: {}/{} ({:.2}%)</li>\n",
+            coverage.num_covered_lines, coverage.total_lines, coverage.total_coverage_percentage
+        ));
+        html.push_str(&format!(
+            "<li>Branches: {}/{}</li>\n",
+            coverage.num_covered_branches, coverage.total_branches
+        ));
+        html.push_str(&format!(
+            "<li>Conditions: {}/{}</li>\n",
+            coverage.num_covered_conditions, coverage.total_conditions
+        ));
+        html.push_str("</ul>\n");
+
+        for file in &coverage.file_coverage {
+            let line_status = build_line_status(file);
+
+            html.push_str(&format!("<h2>{}</h2>\n", html_escape(&file.name)));
+            html.push_str("<table>\n");
+
+            for (index, line) in file.content.lines().enumerate() {
+                let line_number = index + 1;
+                let class = match line_status.get(&line_number) {
+                    Some(LineStatus::Covered) => "covered",
+                    Some(LineStatus::Partial) => "partial",
+                    Some(LineStatus::Uncovered) => "uncovered",
+                    None => "",
+                };
+
+                html.push_str(&format!(
+                    "<tr class=\"{class}\"><td class=\"gutter\">{line_number}</td><td><pre>{}</pre></td></tr>\n",
+                    html_escape(line)
+                ));
+            }
+
+            html.push_str("</table>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+
+        let path = format!("{name}.html");
+        fs::write(&path, html)?;
+        Ok(path)
+    }
+}
+
+/// Whether a rendered source line is fully covered, partially covered (a branch/condition took
+/// only one of its outcomes), or not covered at all.
+enum LineStatus {
+    Covered,
+    Partial,
+    Uncovered,
+}
+
+/// Classifies each of `file`'s instrumented lines into a [`LineStatus`], from its raw traces.
+fn build_line_status(file: &FileCoverage) -> HashMap<usize, LineStatus> {
+    let mut status = HashMap::new();
+
+    for trace in &file.traces {
+        let line_status = match &trace.stats {
+            CoverageStat::Line(hits) if *hits > 0 => LineStatus::Covered,
+            CoverageStat::Line(_) => LineStatus::Uncovered,
+            CoverageStat::Branch(branch) if branch.is_covered() => LineStatus::Covered,
+            CoverageStat::Branch(branch) if branch.been_true || branch.been_false => {
+                LineStatus::Partial
+            }
+            CoverageStat::Branch(_) => LineStatus::Uncovered,
+            CoverageStat::Condition(conditions) => {
+                let covered = conditions.iter().filter(|c| c.is_covered()).count();
+                let taken = conditions
+                    .iter()
+                    .filter(|c| c.been_true || c.been_false)
+                    .count();
+                if covered == conditions.len() {
+                    LineStatus::Covered
+                } else if taken > 0 {
+                    LineStatus::Partial
+                } else {
+                    LineStatus::Uncovered
+                }
+            }
+        };
+
+        status.insert(trace.line, line_status);
+    }
+
+    status
+}
+
+/// Escapes `&`, `<`, and `>` so file names and source text can be embedded directly in HTML.
+fn html_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Writes SonarQube/SonarCloud's "generic coverage" XML, consumed via
+/// `sonar.coverageReportPaths`.
+pub struct SonarCoverageReporter;
+
+impl CoverageReporter for SonarCoverageReporter {
+    fn write(
+        &self,
+        coverage: &Coverage,
+        name: &str,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<coverage version=\"1\">\n");
+
+        for file in &coverage.file_coverage {
+            xml.push_str(&format!("  <file path=\"{}\">\n", file.name));
+
+            for trace in &file.traces {
+                let (covered, branches_to_cover, covered_branches) = match &trace.stats {
+                    CoverageStat::Line(hits) => (*hits > 0, 0, 0),
+                    CoverageStat::Branch(branch) => (
+                        branch.been_true || branch.been_false,
+                        2,
+                        u32::from(branch.been_true) + u32::from(branch.been_false),
+                    ),
+                    CoverageStat::Condition(conditions) => (
+                        conditions.iter().any(|c| c.been_true || c.been_false),
+                        conditions.len() as u32 * 2,
+                        conditions
+                            .iter()
+                            .map(|c| u32::from(c.been_true) + u32::from(c.been_false))
+                            .sum(),
+                    ),
+                };
+
+                if branches_to_cover > 0 {
+                    xml.push_str(&format!(
+                        "    <lineToCover lineNumber=\"{}\" covered=\"{covered}\" branchesToCover=\"{branches_to_cover}\" coveredBranches=\"{covered_branches}\"/>\n",
+                        trace.line
+                    ));
+                } else {
+                    xml.push_str(&format!(
+                        "    <lineToCover lineNumber=\"{}\" covered=\"{covered}\"/>\n",
+                        trace.line
+                    ));
+                }
+            }
+
+            xml.push_str("  </file>\n");
+        }
+
+        xml.push_str("</coverage>\n");
+
+        let path = format!("{name}.xml");
+        fs::write(&path, xml)?;
+        Ok(path)
+    }
+}
+
+/// `covered / total`, or `0.0` when `total` is zero (an empty file/report is vacuously
+/// "fully covered" in neither direction, but dividing by zero is worse).
+fn rate(covered: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        f64::from(covered) / f64::from(total)
+    }
+}
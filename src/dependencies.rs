@@ -1,24 +1,201 @@
-use crate::command::execute_command_no_path;
+use crate::command::{is_installed, Captured, Cmd};
+use crate::error::Error;
 use ansi_term::Colour::{Green, Red, Yellow};
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
 
-/// List of Rustup components to install.
-pub const RUSTUP_COMPONENT_LIST: &[&str] = &["cargo-clippy", "rustfmt"];
-
-/// List of system binaries to check.
-pub const SYSTEM_BINARY_LIST: &[&str] = &["git"];
-
-/// List of Cargo subcommands to install.
-pub const CARGO_SUBCOMMAND_LIST: &[&str] = &[
-    "cargo-outdated",
-    "cargo-audit",
-    "cargo-tarpaulin",
-    "cargo-crev",
-    "cargo-install-update",
-    "cargo-expand",
-    "cargo-modules",
-    // "cargo-nextest",
-    "tokei",
-];
+/// A single Rustup component to install, resolved from either the built-in defaults (see
+/// [`default_rustup_components`]) or a `[[rustup_components]]` entry in a [`DependencyManifest`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct RustupComponent {
+    /// The component's binary name, as checked by [`is_installed`] (e.g. `cargo-clippy`,
+    /// `rustfmt`) and shown in reports.
+    pub name: String,
+    /// The literal name `rustup component add` expects (e.g. `clippy`, `rustfmt`, `rust-src`).
+    /// Defaults to `name` when unset, which is correct whenever a component's rustup name and
+    /// binary name coincide (e.g. `rustfmt`); a component like `clippy`, whose binary is
+    /// `cargo-clippy`, must set this explicitly.
+    #[serde(default)]
+    pub rustup_name: Option<String>,
+    /// The toolchain to install the component into (`rustup component add --toolchain ...`).
+    /// `None` installs into the active toolchain.
+    #[serde(default)]
+    pub toolchain: Option<String>,
+}
+
+impl RustupComponent {
+    /// The name to pass to `rustup component add`: [`RustupComponent::rustup_name`] if set,
+    /// otherwise [`RustupComponent::name`].
+    fn rustup_name(&self) -> &str {
+        self.rustup_name.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// The built-in Rustup components, used when no [`DependencyManifest`] configures its own.
+fn default_rustup_components() -> Vec<RustupComponent> {
+    vec![
+        RustupComponent {
+            name: "cargo-clippy".to_string(),
+            rustup_name: Some("clippy".to_string()),
+            toolchain: None,
+        },
+        RustupComponent {
+            name: "rustfmt".to_string(),
+            rustup_name: None,
+            toolchain: Some("stable".to_string()),
+        },
+    ]
+}
+
+/// The built-in system binaries, used when no [`DependencyManifest`] configures its own.
+fn default_system_binaries() -> Vec<String> {
+    vec!["git".to_string()]
+}
+
+/// A Cargo subcommand this crate depends on, resolved from either the built-in defaults (see
+/// [`default_cargo_subcommands`]) or a `[[cargo_subcommands]]` entry in a [`DependencyManifest`].
+///
+/// `crate_name` is what `cargo install` and `cargo install --list` key off of, which is not
+/// always the binary the subcommand installs (e.g. the crate `cargo-update` installs the
+/// `cargo-install-update` binary). `requirement` is a `semver::VersionReq` string the installed
+/// version must satisfy; `None` means any installed version is acceptable. `extra_args` carries
+/// install-time flags like `--locked` for `cargo-outdated`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CargoSubcommand {
+    pub crate_name: String,
+    #[serde(default)]
+    pub requirement: Option<String>,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+}
+
+impl CargoSubcommand {
+    fn new(crate_name: &str) -> Self {
+        Self {
+            crate_name: crate_name.to_string(),
+            requirement: None,
+            extra_args: Vec::new(),
+        }
+    }
+
+    fn with_requirement(mut self, requirement: &str) -> Self {
+        self.requirement = Some(requirement.to_string());
+        self
+    }
+
+    fn with_extra_args(mut self, args: &[&str]) -> Self {
+        self.extra_args = args.iter().map(|arg| arg.to_string()).collect();
+        self
+    }
+}
+
+/// The built-in Cargo subcommands, used when no [`DependencyManifest`] configures its own.
+fn default_cargo_subcommands() -> Vec<CargoSubcommand> {
+    vec![
+        CargoSubcommand::new("cargo-outdated").with_extra_args(&["--locked"]),
+        CargoSubcommand::new("cargo-audit"),
+        CargoSubcommand::new("cargo-tarpaulin"),
+        CargoSubcommand::new("cargo-crev"),
+        CargoSubcommand::new("cargo-update"),
+        CargoSubcommand::new("cargo-expand"),
+        CargoSubcommand::new("cargo-modules").with_requirement("=0.5.14"),
+        // CargoSubcommand::new("cargo-nextest"),
+        CargoSubcommand::new("tokei"),
+    ]
+}
+
+/// Paths checked by [`DependencyManifest::discover`], in priority order.
+const MANIFEST_PATHS: &[&str] = &["rca.toml", ".config/rca.toml"];
+
+/// A TOML manifest describing the Rustup components, Cargo subcommands, and system binaries
+/// the dependency installer should manage (`rca.toml` / `.config/rca.toml`), so teams can add
+/// tools like `cargo-deny` or `cargo-nextest`, or drop ones they don't want, without forking
+/// the crate.
+///
+/// Each field defaults to `None`, meaning "not configured" rather than "configured as empty": a
+/// manifest that only sets `cargo_subcommands` still gets the built-in Rustup components and
+/// system binaries (see [`DependencyManifest::rustup_components`] and friends), so a team can
+/// override a single category without having to restate the other two.
+///
+/// # Example
+///
+/// ```toml
+/// [[cargo_subcommands]]
+/// crate_name = "cargo-deny"
+///
+/// [[cargo_subcommands]]
+/// crate_name = "cargo-nextest"
+/// requirement = ">=0.9"
+///
+/// [[rustup_components]]
+/// name = "rustfmt"
+/// toolchain = "nightly"
+///
+/// [[rustup_components]]
+/// name = "cargo-clippy"
+/// rustup_name = "clippy"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct DependencyManifest {
+    #[serde(default)]
+    rustup_components: Option<Vec<RustupComponent>>,
+    #[serde(default)]
+    cargo_subcommands: Option<Vec<CargoSubcommand>>,
+    #[serde(default)]
+    system_binaries: Option<Vec<String>>,
+}
+
+impl DependencyManifest {
+    /// Loads a manifest from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain valid TOML.
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Loads a manifest from the first of [`MANIFEST_PATHS`] that exists, or the built-in
+    /// defaults (an empty manifest) if none do.
+    pub fn discover() -> Self {
+        MANIFEST_PATHS
+            .iter()
+            .map(Path::new)
+            .find(|path| path.exists())
+            .and_then(|path| Self::from_file(path).ok())
+            .unwrap_or_default()
+    }
+
+    /// The Rustup components to install: the manifest's, if configured, otherwise the built-in
+    /// defaults (see [`default_rustup_components`]).
+    pub fn rustup_components(&self) -> Vec<RustupComponent> {
+        self.rustup_components
+            .clone()
+            .unwrap_or_else(default_rustup_components)
+    }
+
+    /// The Cargo subcommands to install: the manifest's, if configured, otherwise the built-in
+    /// defaults (see [`default_cargo_subcommands`]).
+    pub fn cargo_subcommands(&self) -> Vec<CargoSubcommand> {
+        self.cargo_subcommands
+            .clone()
+            .unwrap_or_else(default_cargo_subcommands)
+    }
+
+    /// The system binaries to check for: the manifest's, if configured, otherwise the built-in
+    /// defaults (see [`default_system_binaries`]).
+    pub fn system_binaries(&self) -> Vec<String> {
+        self.system_binaries
+            .clone()
+            .unwrap_or_else(default_system_binaries)
+    }
+}
 
 /// Represents the kinds of dependencies.
 #[derive(Debug, Clone, Copy)]
@@ -32,29 +209,53 @@ pub enum Kind {
 }
 
 /// Represents errors related to dependency management.
+///
+/// Where a failure can come from a command that was retried (see [`with_retries`]), the variant
+/// carries how many attempts were made, so a persistent failure can be told apart from one that
+/// merely hit a transient network blip and gave up after exhausting its retries.
 #[derive(Debug, thiserror::Error)]
 pub enum DependencyError {
     /// Error updating Rustup and Cargo.
-    #[error("Update Error")]
-    UpdateFailed,
+    #[error("Update Error (after {attempts} attempt(s))")]
+    UpdateFailed { attempts: u32 },
 
-    /// Error installing Rustup components.
+    /// Error installing Rustup components. Each entry is `(component, attempts made)`.
     #[error("Rustup Component {:?} Installation Failed", .0)]
-    ComponentInstallFailed(Vec<String>),
+    ComponentInstallFailed(Vec<(String, u32)>),
 
-    /// Error installing Cargo subcommands.
+    /// Error installing Cargo subcommands. Each entry is `(crate_name, attempts made)`.
     #[error("Cargo Subcommand {:?} Installation Failed", .0)]
-    SubcommandsInstallFailed(Vec<String>),
+    SubcommandsInstallFailed(Vec<(String, u32)>),
+
+    /// A forced reinstall of an outdated (or mis-pinned) Cargo subcommand failed. Each entry is
+    /// `(crate_name, attempts made)`.
+    #[error("Cargo Subcommand {:?} Upgrade Failed", .0)]
+    SubcommandOutdated(Vec<(String, u32)>),
 
     /// System binary not installed or not in PATH.
     #[error("System Binary {:?} Are Not Installed or Not In $PATH", .0)]
     SystemBinariesNotInstalled(Vec<String>),
+
+    /// Failed to enumerate installed Rustup toolchains (`rustup toolchain list`), so
+    /// [`install_and_verify_toolchains`] has no targets to work from.
+    #[error("Failed To List Rustup Toolchains")]
+    ToolchainListFailed,
+
+    /// Rustup component installation failed on a specific toolchain, as part of
+    /// [`install_and_verify_toolchains`]. Each entry is `(component, attempts made)`.
+    #[error("Rustup Component {:?} Installation Failed On Toolchain {toolchain}", .components)]
+    ComponentInstallFailedForToolchain {
+        toolchain: String,
+        components: Vec<(String, u32)>,
+    },
 }
 
 /// Installs and updates Rust toolchain dependencies.
 ///
 /// This function updates Rustup and Cargo, installs Rustup components, Cargo subcommands,
-/// and checks for the presence of required system binaries.
+/// and checks for the presence of required system binaries, using the first of
+/// [`DependencyManifest::discover`]'s candidate paths that exists, or the crate's built-in
+/// defaults if none do.
 ///
 /// # Returns
 ///
@@ -71,13 +272,24 @@ pub enum DependencyError {
 ///     Err(errors) => {
 ///         for error in errors {
 ///             match error {
-///                 DependencyError::UpdateFailed => println!("Failed to update Rustup and Cargo."),
+///                 DependencyError::UpdateFailed { attempts } => {
+///                     println!("Failed to update Rustup and Cargo after {attempts} attempt(s).");
+///                 }
 ///                 DependencyError::ComponentInstallFailed(components) => {
 ///                     println!("Failed to install Rustup components: {:?}", components);
 ///                 }
 ///                 DependencyError::SubcommandsInstallFailed(subcommands) => {
 ///                     println!("Failed to install Cargo subcommands: {:?}", subcommands);
 ///                 }
+///                 DependencyError::SubcommandOutdated(subcommands) => {
+///                     println!("Failed to upgrade outdated Cargo subcommands: {:?}", subcommands);
+///                 }
+///                 DependencyError::ToolchainListFailed => {
+///                     println!("Failed to list Rustup toolchains.");
+///                 }
+///                 DependencyError::ComponentInstallFailedForToolchain { toolchain, components } => {
+///                     println!("Failed to install Rustup components on {toolchain}: {:?}", components);
+///                 }
 ///                 DependencyError::SystemBinariesNotInstalled(binaries) => {
 ///                     println!("Required system binaries not found: {:?}", binaries);
 ///                 }
@@ -87,6 +299,14 @@ pub enum DependencyError {
 /// }
 /// ```
 pub fn update_and_install_dependencies() -> Result<(), Vec<DependencyError>> {
+    update_and_install_dependencies_from(&DependencyManifest::discover())
+}
+
+/// Like [`update_and_install_dependencies`], but against an explicit `manifest` instead of
+/// auto-discovering one via [`DependencyManifest::discover`].
+pub fn update_and_install_dependencies_from(
+    manifest: &DependencyManifest,
+) -> Result<(), Vec<DependencyError>> {
     let mut dependency_error: Vec<DependencyError> = Vec::new();
 
     println!(
@@ -103,7 +323,7 @@ pub fn update_and_install_dependencies() -> Result<(), Vec<DependencyError>> {
             .italic()
             .paint("[2/5] Installing Rustup components...")
     );
-    if let Err(error) = install_rustup_components() {
+    if let Err(error) = install_rustup_components(&manifest.rustup_components()) {
         dependency_error.push(error);
     }
 
@@ -113,7 +333,7 @@ pub fn update_and_install_dependencies() -> Result<(), Vec<DependencyError>> {
             .italic()
             .paint("[3/5] Installing Cargo subcommands...")
     );
-    if let Err(error) = install_cargo_subcommands() {
+    if let Err(error) = install_cargo_subcommands(&manifest.cargo_subcommands()) {
         dependency_error.push(error);
     }
 
@@ -121,7 +341,7 @@ pub fn update_and_install_dependencies() -> Result<(), Vec<DependencyError>> {
         "{}",
         Yellow.italic().paint("[4/5] Checking system binaries...")
     );
-    if let Err(error) = check_system_binaries() {
+    if let Err(error) = check_system_binaries(&manifest.system_binaries()) {
         dependency_error.push(error);
     }
 
@@ -146,57 +366,137 @@ pub fn update_and_install_dependencies() -> Result<(), Vec<DependencyError>> {
     }
 }
 
-/// Checks if a binary is installed and in PATH.
-///
-/// # Arguments
-///
-/// * `name` - The name of the binary to check.
-///
-/// # Returns
+/// Default number of attempts [`with_retries`] makes before giving up.
+const DEFAULT_RETRIES: u32 = 3;
+
+/// Default base delay for [`with_retries`]'s exponential backoff between attempts.
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Whether `stderr` looks like a transient network failure (a dropped connection, a timeout, a
+/// TLS handshake failure, or a 5xx from the registry) rather than a genuine build/install error
+/// that retrying would not fix.
+fn is_transient_network_error(stderr: &str) -> bool {
+    const NEEDLES: &[&str] = &[
+        "connection reset",
+        "connection refused",
+        "timed out",
+        "timeout",
+        "tls",
+        "could not connect",
+        "network is unreachable",
+        "temporary failure in name resolution",
+        "502 bad gateway",
+        "503 service unavailable",
+        "504 gateway",
+    ];
+
+    let stderr = stderr.to_lowercase();
+    NEEDLES.iter().any(|needle| stderr.contains(needle))
+}
+
+/// Whether a `Cmd::capture()` result represents a failure: either the process could not be
+/// spawned at all, or it ran and exited non-zero. A bare `.is_err()` check on the `Result`
+/// misses the latter entirely, since [`Cmd::capture`] only returns `Err` for a missing tool or a
+/// spawn/IO failure — a command that runs and fails (including a transient error that exhausted
+/// all of [`with_retries`]'s attempts) comes back as `Ok(Captured { status: <nonzero>, .. })`.
+fn command_failed(result: &Result<Captured, Error>) -> bool {
+    match result {
+        Ok(captured) => !captured.success(),
+        Err(_) => true,
+    }
+}
+
+/// Runs `command` up to `retries` times (minimum `1`), waiting `base_delay * 2^attempt` between
+/// attempts, but only retries a failure [`is_transient_network_error`] recognizes as
+/// network-related; a genuine build/install error is returned immediately without retrying.
 ///
-/// `true` if the binary is installed and in PATH, `false` otherwise.
-fn is_installed(name: &str) -> bool {
-    which::which(name).is_ok()
+/// Returns the last result alongside how many attempts were made, so callers can record in a
+/// [`DependencyError`] whether a failure was persistent or merely exhausted its retries.
+fn with_retries<F>(retries: u32, base_delay: Duration, mut command: F) -> (Result<Captured, Error>, u32)
+where
+    F: FnMut() -> Result<Captured, Error>,
+{
+    let retries = retries.max(1);
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        let result = command();
+
+        let is_transient = match &result {
+            Ok(captured) => !captured.success() && is_transient_network_error(&captured.stderr),
+            Err(_) => false,
+        };
+
+        if attempts >= retries || !is_transient {
+            return (result, attempts);
+        }
+
+        std::thread::sleep(base_delay * 2u32.pow(attempts - 1));
+    }
 }
 
-/// Updates Rustup and Cargo.
+/// Updates Rustup and Cargo, making up to [`DEFAULT_RETRIES`] attempts with exponential backoff
+/// on failures that look network-related (see [`with_retries`]).
 ///
 /// # Returns
 ///
 /// `Ok(())` if the update is successful, `Err(DependencyError)` if the update fails.
 pub fn update() -> Result<(), DependencyError> {
-    let update_1 = execute_command_no_path("rustup", &["self", "update"], false);
-    let update_2 = execute_command_no_path("rustup", &["update"], false);
+    update_with_retries(DEFAULT_RETRIES, DEFAULT_RETRY_DELAY)
+}
+
+/// Like [`update`], but with an explicit retry count and base backoff delay.
+pub fn update_with_retries(retries: u32, base_delay: Duration) -> Result<(), DependencyError> {
+    let (update_1, attempts_1) = with_retries(retries, base_delay, || {
+        Cmd::new("rustup").args(["self", "update"]).capture()
+    });
+    let (update_2, attempts_2) = with_retries(retries, base_delay, || {
+        Cmd::new("rustup").args(["update"]).capture()
+    });
 
-    match update_1.is_err() || update_2.is_err() {
-        true => Err(DependencyError::UpdateFailed),
-        false => Ok(()),
+    match (command_failed(&update_1), command_failed(&update_2)) {
+        (false, false) => Ok(()),
+        (true, _) => Err(DependencyError::UpdateFailed {
+            attempts: attempts_1,
+        }),
+        (false, true) => Err(DependencyError::UpdateFailed {
+            attempts: attempts_2,
+        }),
     }
 }
 
-/// Installs required Rustup components.
+/// Installs `components`, making up to [`DEFAULT_RETRIES`] attempts with exponential backoff on
+/// failures that look network-related (see [`with_retries`]).
 ///
 /// # Returns
 ///
 /// `Ok(())` if installation is successful, `Err(DependencyError)` if installation fails.
-pub fn install_rustup_components() -> Result<(), DependencyError> {
-    let mut install_failed: Vec<String> = Vec::new();
+pub fn install_rustup_components(components: &[RustupComponent]) -> Result<(), DependencyError> {
+    install_rustup_components_with_retries(components, DEFAULT_RETRIES, DEFAULT_RETRY_DELAY)
+}
 
-    for component in RUSTUP_COMPONENT_LIST {
-        if !is_installed(component) {
-            let args: Vec<&str> = if component == &"rustfmt" {
-                vec!["component", "add", component, "--toolchain", "stable"]
-            } else {
-                vec![
-                    "component",
-                    "add",
-                    component.split('-').collect::<Vec<&str>>()[1],
-                ]
-            };
+/// Like [`install_rustup_components`], but with an explicit retry count and base backoff delay.
+pub fn install_rustup_components_with_retries(
+    components: &[RustupComponent],
+    retries: u32,
+    base_delay: Duration,
+) -> Result<(), DependencyError> {
+    let mut install_failed: Vec<(String, u32)> = Vec::new();
 
-            if execute_command_no_path("rustup", &args, false).is_err() {
-                install_failed.push(component.to_string());
-            }
+    for component in components {
+        if is_installed(&component.name) {
+            continue;
+        }
+
+        let (result, attempts) = with_retries(retries, base_delay, || {
+            Cmd::new("rustup")
+                .args(rustup_component_args(component))
+                .capture()
+        });
+
+        if command_failed(&result) {
+            install_failed.push((component.name.clone(), attempts));
         }
     }
 
@@ -206,47 +506,428 @@ pub fn install_rustup_components() -> Result<(), DependencyError> {
     }
 }
 
-/// Installs required Cargo subcommands.
+/// The `rustup component add` arguments for `component`.
+fn rustup_component_args(component: &RustupComponent) -> Vec<&str> {
+    let mut args = vec!["component", "add", component.rustup_name()];
+
+    if let Some(toolchain) = &component.toolchain {
+        args.extend(["--toolchain", toolchain]);
+    }
+
+    args
+}
+
+/// Parses the output of `cargo install --list` into a map of crate name to installed version.
+///
+/// The output looks like:
+///
+/// ```text
+/// cargo-audit v0.17.0:
+///     cargo-audit
+/// cargo-update v11.1.2:
+///     cargo-install-update
+///     cargo-install-update-config
+/// ```
+///
+/// Only the unindented `<crate> v<version>:` lines are parsed; the indented binary-name lines
+/// that follow each are irrelevant here since subcommands are tracked, installed, and upgraded
+/// by crate name (see [`CargoSubcommand::crate_name`]).
+fn parse_installed_versions(list_output: &str) -> HashMap<String, Version> {
+    let mut installed = HashMap::new();
+
+    for line in list_output.lines() {
+        if line.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let Some((name, version)) = line.rsplit_once(' ') else {
+            continue;
+        };
+
+        let Some(version) = version.trim_end_matches(':').strip_prefix('v') else {
+            continue;
+        };
+
+        if let Ok(version) = Version::parse(version) {
+            installed.insert(name.to_string(), version);
+        }
+    }
+
+    installed
+}
+
+/// Whether `subcommand` needs to be installed (or reinstalled): it is missing entirely, or it
+/// is installed but the installed version fails `subcommand.requirement`.
+fn needs_install(subcommand: &CargoSubcommand, installed: &HashMap<String, Version>) -> bool {
+    let Some(installed_version) = installed.get(&subcommand.crate_name) else {
+        return true;
+    };
+
+    subcommand.requirement.as_deref().is_some_and(|requirement| {
+        VersionReq::parse(requirement)
+            .is_ok_and(|requirement| !requirement.matches(installed_version))
+    })
+}
+
+/// Installs `subcommands`, upgrading (`cargo install --force`) any that are already installed
+/// but whose version fails their `VersionReq`, making up to [`DEFAULT_RETRIES`] attempts with
+/// exponential backoff on failures that look network-related (see [`with_retries`]).
 ///
 /// # Returns
 ///
-/// `Ok(())` if installation is successful, `Err(DependencyError)` if installation fails.
-fn install_cargo_subcommands() -> Result<(), DependencyError> {
-    let mut install_failed: Vec<String> = Vec::new();
-
-    for subcommand in CARGO_SUBCOMMAND_LIST {
-        if !is_installed(subcommand) {
-            let args: Vec<&str> = if subcommand == &"cargo-outdated" {
-                vec!["install", "--locked", subcommand]
-            } else if subcommand == &"cargo-install-update" {
-                vec!["install", "cargo-update"]
-            } else if subcommand == &"cargo-modules" {
-                vec!["install", subcommand, "--version", "0.5.14"]
-            } else {
-                vec!["install", subcommand]
-            };
+/// `Ok(())` if every subcommand ends up installed at a satisfying version. Otherwise
+/// `Err(DependencyError::SubcommandOutdated)` if a forced upgrade of an already-installed
+/// subcommand failed, or `Err(DependencyError::SubcommandsInstallFailed)` if a fresh install
+/// failed.
+pub fn install_cargo_subcommands(subcommands: &[CargoSubcommand]) -> Result<(), DependencyError> {
+    install_cargo_subcommands_with_retries(subcommands, DEFAULT_RETRIES, DEFAULT_RETRY_DELAY)
+}
+
+/// Like [`install_cargo_subcommands`], but with an explicit retry count and base backoff delay.
+pub fn install_cargo_subcommands_with_retries(
+    subcommands: &[CargoSubcommand],
+    retries: u32,
+    base_delay: Duration,
+) -> Result<(), DependencyError> {
+    let mut install_failed: Vec<(String, u32)> = Vec::new();
+    let mut upgrade_failed: Vec<(String, u32)> = Vec::new();
 
-            if execute_command_no_path("cargo", &args, false).is_err() {
-                install_failed.push(subcommand.to_string());
+    let installed = Cmd::new("cargo")
+        .args(["install", "--list"])
+        .capture()
+        .map(|captured| parse_installed_versions(&captured.stdout))
+        .unwrap_or_default();
+
+    for subcommand in subcommands {
+        if !needs_install(subcommand, &installed) {
+            continue;
+        }
+
+        let was_installed = installed.contains_key(&subcommand.crate_name);
+
+        let (result, attempts) = with_retries(retries, base_delay, || {
+            Cmd::new("cargo")
+                .args(cargo_subcommand_args(subcommand, was_installed))
+                .capture()
+        });
+
+        if command_failed(&result) {
+            if was_installed {
+                upgrade_failed.push((subcommand.crate_name.clone(), attempts));
+            } else {
+                install_failed.push((subcommand.crate_name.clone(), attempts));
             }
         }
     }
 
+    if !upgrade_failed.is_empty() {
+        return Err(DependencyError::SubcommandOutdated(upgrade_failed));
+    }
+
+    match install_failed.is_empty() {
+        true => Ok(()),
+        false => Err(DependencyError::SubcommandsInstallFailed(install_failed)),
+    }
+}
+
+/// The `cargo install` arguments for `subcommand`, forcing a reinstall (`--force`) when `force`
+/// is set (i.e. an already-installed version failed its `VersionReq` and needs upgrading).
+fn cargo_subcommand_args(subcommand: &CargoSubcommand, force: bool) -> Vec<&str> {
+    let mut args = vec!["install", subcommand.crate_name.as_str()];
+    args.extend(subcommand.extra_args.iter().map(String::as_str));
+
+    if let Some(requirement) = &subcommand.requirement {
+        args.extend(["--version", requirement.as_str()]);
+    }
+
+    if force {
+        args.push("--force");
+    }
+
+    args
+}
+
+/// The outcome of a single `rustup component add` / `cargo install` invocation, as run by
+/// [`install_concurrently`].
+enum InstallOutcome {
+    /// The tool is installed (or already was).
+    Success,
+    /// The invocation failed because `cargo install`'s global registry/index lock was held by
+    /// another concurrent invocation, rather than a genuine build/install error.
+    LockContended,
+    /// The invocation failed for any other reason.
+    Failed,
+}
+
+/// Whether `stderr` looks like `cargo install`'s global registry/index lock being contended by
+/// another concurrent invocation, as opposed to a genuine build/install failure.
+fn is_lock_contention(stderr: &str) -> bool {
+    stderr.contains("waiting for file lock")
+}
+
+/// Classifies the result of a `Cmd::capture()` install invocation into an [`InstallOutcome`].
+///
+/// Like [`command_failed`] in the sequential install loops, this treats both a failure to spawn
+/// the process and a non-zero exit as a failure; in addition, it tells a non-zero exit caused by
+/// lock contention apart from a genuine build failure, so the concurrent pool can decide whether
+/// to retry.
+fn install_outcome(result: Result<Captured, Error>) -> InstallOutcome {
+    match result {
+        Ok(captured) if captured.success() => InstallOutcome::Success,
+        Ok(captured) if is_lock_contention(&captured.stderr) => InstallOutcome::LockContended,
+        _ => InstallOutcome::Failed,
+    }
+}
+
+/// Runs `install` for each of `items` across a worker pool capped at `parallelism` concurrent
+/// threads, returning the `(label, item)` pairs that failed.
+///
+/// Items whose `install` reports [`InstallOutcome::LockContended`] are not treated as failed
+/// outright: they're deferred and retried serially once the rest of the pool has drained, since
+/// `cargo install` taking its global registry/index lock is expected under concurrency and
+/// should not fail a tool that would otherwise install fine once the lock frees up.
+fn install_concurrently<T, F>(items: Vec<T>, parallelism: usize, install: F) -> Vec<(String, T)>
+where
+    T: Send + 'static,
+    F: Fn(&T) -> (String, InstallOutcome) + Send + Sync,
+{
+    let queue = Mutex::new(VecDeque::from(items));
+    let failed = Mutex::new(Vec::new());
+    let deferred = Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..parallelism {
+            scope.spawn(|| loop {
+                let Some(item) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+
+                match install(&item) {
+                    (_, InstallOutcome::Success) => {}
+                    (label, InstallOutcome::LockContended) => {
+                        deferred.lock().unwrap().push((label, item));
+                    }
+                    (label, InstallOutcome::Failed) => {
+                        failed.lock().unwrap().push((label, item));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut failed = failed.into_inner().unwrap();
+
+    for (label, item) in deferred.into_inner().unwrap() {
+        if !matches!(install(&item).1, InstallOutcome::Success) {
+            failed.push((label, item));
+        }
+    }
+
+    failed
+}
+
+/// The level of parallelism [`update_and_install_dependencies_parallel`] defaults to when not
+/// given one explicitly: the number of available CPUs, or `1` if that can't be determined.
+fn default_parallelism() -> usize {
+    std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+/// Installs `components` across up to `parallelism` concurrent `rustup` invocations. Unlike
+/// [`install_rustup_components`], failures are not retried, so every recorded attempt count is
+/// `1`.
+///
+/// # Returns
+///
+/// `Ok(())` if installation is successful, `Err(DependencyError)` if installation fails.
+fn install_rustup_components_parallel(
+    components: &[RustupComponent],
+    parallelism: usize,
+) -> Result<(), DependencyError> {
+    let missing: Vec<RustupComponent> = components
+        .iter()
+        .filter(|component| !is_installed(&component.name))
+        .cloned()
+        .collect();
+
+    let failed: Vec<(String, u32)> = install_concurrently(missing, parallelism, |component| {
+        let outcome = install_outcome(
+            Cmd::new("rustup")
+                .args(rustup_component_args(component))
+                .capture(),
+        );
+        (component.name.clone(), outcome)
+    })
+    .into_iter()
+    .map(|(label, _)| (label, 1))
+    .collect();
+
+    match failed.is_empty() {
+        true => Ok(()),
+        false => Err(DependencyError::ComponentInstallFailed(failed)),
+    }
+}
+
+/// Installs `subcommands` across up to `parallelism` concurrent `cargo install` invocations,
+/// upgrading (`--force`) any already installed at a version failing their `VersionReq`. Unlike
+/// [`install_cargo_subcommands`], failures are not retried, so every recorded attempt count is
+/// `1`.
+///
+/// # Returns
+///
+/// `Ok(())` if every subcommand ends up installed at a satisfying version. Otherwise
+/// `Err(DependencyError::SubcommandOutdated)` if an upgrade of an already-installed subcommand
+/// failed, or `Err(DependencyError::SubcommandsInstallFailed)` if a fresh install failed.
+fn install_cargo_subcommands_parallel(
+    subcommands: &[CargoSubcommand],
+    parallelism: usize,
+) -> Result<(), DependencyError> {
+    let installed = Cmd::new("cargo")
+        .args(["install", "--list"])
+        .capture()
+        .map(|captured| parse_installed_versions(&captured.stdout))
+        .unwrap_or_default();
+
+    let to_install: Vec<(CargoSubcommand, bool)> = subcommands
+        .iter()
+        .filter(|subcommand| needs_install(subcommand, &installed))
+        .map(|subcommand| {
+            let was_installed = installed.contains_key(&subcommand.crate_name);
+            (subcommand.clone(), was_installed)
+        })
+        .collect();
+
+    let failed = install_concurrently(to_install, parallelism, |(subcommand, was_installed)| {
+        let outcome = install_outcome(
+            Cmd::new("cargo")
+                .args(cargo_subcommand_args(subcommand, *was_installed))
+                .capture(),
+        );
+        (subcommand.crate_name.clone(), outcome)
+    });
+
+    let (upgrade_failed, install_failed): (Vec<_>, Vec<_>) = failed
+        .into_iter()
+        .partition(|(_, (_, was_installed))| *was_installed);
+    let upgrade_failed: Vec<(String, u32)> = upgrade_failed
+        .into_iter()
+        .map(|(label, _)| (label, 1))
+        .collect();
+    let install_failed: Vec<(String, u32)> = install_failed
+        .into_iter()
+        .map(|(label, _)| (label, 1))
+        .collect();
+
+    if !upgrade_failed.is_empty() {
+        return Err(DependencyError::SubcommandOutdated(upgrade_failed));
+    }
+
     match install_failed.is_empty() {
         true => Ok(()),
         false => Err(DependencyError::SubcommandsInstallFailed(install_failed)),
     }
 }
 
-/// Checks required system binaries are installed.
+/// Like [`update_and_install_dependencies`], but installs Rustup components and Cargo
+/// subcommands concurrently across a bounded worker pool instead of one at a time, which
+/// matters most on a cold setup where a dozen `cargo install`s would otherwise compile from
+/// source one after another.
+///
+/// # Arguments
+///
+/// * `parallelism` - The maximum number of concurrent `rustup`/`cargo` invocations. `None`
+///   defaults to the available CPU count (see [`default_parallelism`]).
+///
+/// # Returns
+///
+/// - `Ok(())` if no errors are encountered.
+/// - `Err(Vec<DependencyError>)` if any errors are encountered during dependency installation or checks.
+pub fn update_and_install_dependencies_parallel(
+    parallelism: Option<usize>,
+) -> Result<(), Vec<DependencyError>> {
+    update_and_install_dependencies_parallel_from(&DependencyManifest::discover(), parallelism)
+}
+
+/// Like [`update_and_install_dependencies_parallel`], but against an explicit `manifest` instead
+/// of auto-discovering one via [`DependencyManifest::discover`].
+pub fn update_and_install_dependencies_parallel_from(
+    manifest: &DependencyManifest,
+    parallelism: Option<usize>,
+) -> Result<(), Vec<DependencyError>> {
+    let parallelism = parallelism.unwrap_or_else(default_parallelism).max(1);
+    let mut dependency_error: Vec<DependencyError> = Vec::new();
+
+    println!(
+        "{}",
+        Yellow.italic().paint("[1/5] Updating Rustup and Cargo...")
+    );
+    if let Err(error) = update() {
+        dependency_error.push(error);
+    }
+
+    println!(
+        "{}",
+        Yellow
+            .italic()
+            .paint("[2/5] Installing Rustup components...")
+    );
+    if let Err(error) =
+        install_rustup_components_parallel(&manifest.rustup_components(), parallelism)
+    {
+        dependency_error.push(error);
+    }
+
+    println!(
+        "{}",
+        Yellow
+            .italic()
+            .paint("[3/5] Installing Cargo subcommands...")
+    );
+    if let Err(error) =
+        install_cargo_subcommands_parallel(&manifest.cargo_subcommands(), parallelism)
+    {
+        dependency_error.push(error);
+    }
+
+    println!(
+        "{}",
+        Yellow.italic().paint("[4/5] Checking system binaries...")
+    );
+    if let Err(error) = check_system_binaries(&manifest.system_binaries()) {
+        dependency_error.push(error);
+    }
+
+    println!("{}", Yellow.italic().paint("[5/5] Check errors..."));
+    match dependency_error.is_empty() {
+        true => {
+            println!(
+                "{}",
+                Green.italic().bold().paint("-> No errors encountered.\n")
+            );
+            Ok(())
+        }
+        false => {
+            println!(
+                "{}",
+                Red.italic()
+                    .bold()
+                    .paint(format!("-> Faced {} errors.\n", dependency_error.len()))
+            );
+            Err(dependency_error)
+        }
+    }
+}
+
+/// Checks that `binaries` are installed.
 ///
 /// # Returns
 ///
 /// `Ok(())` if all required system binaries are found, `Err(DependencyError)` if any are missing.
-fn check_system_binaries() -> Result<(), DependencyError> {
+fn check_system_binaries(binaries: &[String]) -> Result<(), DependencyError> {
     let mut not_installed: Vec<String> = Vec::new();
 
-    for binary in SYSTEM_BINARY_LIST {
+    for binary in binaries {
         if !is_installed(binary) {
             not_installed.push(binary.to_string());
         }
@@ -257,3 +938,144 @@ fn check_system_binaries() -> Result<(), DependencyError> {
         false => Err(DependencyError::SystemBinariesNotInstalled(not_installed)),
     }
 }
+
+/// The result of a per-toolchain verification pass (see [`install_and_verify_toolchains`]): the
+/// captured `rustc +<toolchain> -V` and `cargo +<toolchain> -V` output, and which of the
+/// requested Rustup components ended up installed on that toolchain.
+#[derive(Debug)]
+pub struct ToolchainReport {
+    /// The toolchain this report is for (as named by `rustup toolchain list`).
+    pub toolchain: String,
+    /// The `rustc +<toolchain> -V` output, or `None` if it could not be invoked.
+    pub rustc_version: Option<String>,
+    /// The `cargo +<toolchain> -V` output, or `None` if it could not be invoked.
+    pub cargo_version: Option<String>,
+    /// Each requested component's name and whether it ended up installed on this toolchain.
+    pub components: Vec<(String, bool)>,
+}
+
+/// Lists the Rustup toolchains currently installed (`rustup toolchain list`), stripping each
+/// line down to just the toolchain name (dropping the trailing `(default)`/`(override)`
+/// annotation `rustup` prints after the active one).
+fn list_toolchains() -> Result<Vec<String>, Error> {
+    let captured = Cmd::new("rustup").args(["toolchain", "list"]).capture()?;
+
+    Ok(captured
+        .stdout
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect())
+}
+
+/// The Rustup components installed on `toolchain` (`rustup component list --toolchain <toolchain>
+/// --installed`), as the full, target-triple-suffixed names `rustup` reports (e.g.
+/// `rustfmt-x86_64-unknown-linux-gnu`).
+fn installed_components(toolchain: &str) -> Vec<String> {
+    Cmd::new("rustup")
+        .args(["component", "list", "--toolchain", toolchain, "--installed"])
+        .capture()
+        .map(|captured| captured.stdout.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Invokes `<tool> +<toolchain> -V` (`tool` is `"rustc"` or `"cargo"`), returning its trimmed
+/// output, or `None` if it could not be run or exited unsuccessfully (e.g. `toolchain` is not
+/// installed).
+fn toolchain_tool_version(tool: &str, toolchain: &str) -> Option<String> {
+    Cmd::new(tool)
+        .args([format!("+{toolchain}"), "-V".to_string()])
+        .capture()
+        .ok()
+        .filter(Captured::success)
+        .map(|captured| captured.stdout.trim().to_string())
+}
+
+/// Installs and verifies Rustup components across several toolchains at once (e.g. stable +
+/// nightly + a pinned MSRV), rather than just the active one, so a project can guarantee parity
+/// of its required tooling across every toolchain it targets instead of discovering the gap when
+/// CI runs on a toolchain a contributor never tested locally.
+///
+/// # Arguments
+///
+/// * `manifest` - Supplies the Rustup components to install on each toolchain (see
+///   [`DependencyManifest::rustup_components`]); each component's own `toolchain` field is
+///   ignored here in favor of the toolchain currently being iterated.
+/// * `toolchains` - A user-specified subset of toolchains to target. `None` targets every
+///   toolchain currently installed (`rustup toolchain list`).
+///
+/// # Returns
+///
+/// A [`ToolchainReport`] per targeted toolchain, or the [`DependencyError`]s encountered
+/// enumerating toolchains or installing components on one or more of them.
+pub fn install_and_verify_toolchains(
+    manifest: &DependencyManifest,
+    toolchains: Option<&[String]>,
+) -> Result<Vec<ToolchainReport>, Vec<DependencyError>> {
+    let installed_toolchains =
+        list_toolchains().map_err(|_| vec![DependencyError::ToolchainListFailed])?;
+
+    let targets: Vec<String> = match toolchains {
+        Some(requested) => installed_toolchains
+            .into_iter()
+            .filter(|toolchain| requested.contains(toolchain))
+            .collect(),
+        None => installed_toolchains,
+    };
+
+    let components = manifest.rustup_components();
+    let mut errors: Vec<DependencyError> = Vec::new();
+    let mut reports: Vec<ToolchainReport> = Vec::new();
+
+    for toolchain in targets {
+        let mut install_failed: Vec<(String, u32)> = Vec::new();
+
+        for component in &components {
+            let scoped = RustupComponent {
+                name: component.name.clone(),
+                rustup_name: component.rustup_name.clone(),
+                toolchain: Some(toolchain.clone()),
+            };
+
+            let (result, attempts) = with_retries(DEFAULT_RETRIES, DEFAULT_RETRY_DELAY, || {
+                Cmd::new("rustup")
+                    .args(rustup_component_args(&scoped))
+                    .capture()
+            });
+
+            if command_failed(&result) {
+                install_failed.push((component.name.clone(), attempts));
+            }
+        }
+
+        if !install_failed.is_empty() {
+            errors.push(DependencyError::ComponentInstallFailedForToolchain {
+                toolchain: toolchain.clone(),
+                components: install_failed,
+            });
+        }
+
+        let installed = installed_components(&toolchain);
+        let component_status = components
+            .iter()
+            .map(|component| {
+                let present = installed
+                    .iter()
+                    .any(|installed| installed.starts_with(component.rustup_name()));
+                (component.name.clone(), present)
+            })
+            .collect();
+
+        reports.push(ToolchainReport {
+            toolchain: toolchain.clone(),
+            rustc_version: toolchain_tool_version("rustc", &toolchain),
+            cargo_version: toolchain_tool_version("cargo", &toolchain),
+            components: component_status,
+        });
+    }
+
+    match errors.is_empty() {
+        true => Ok(reports),
+        false => Err(errors),
+    }
+}
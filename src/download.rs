@@ -1,8 +1,7 @@
+use crate::{command::Cmd, error::Error};
 use std::{
     env,
-    io::{self, Write},
-    path::PathBuf,
-    process::Command,
+    path::{Path, PathBuf},
 };
 
 /// Creates a `PathBuf` representing a directory path derived from a Git repository URL.
@@ -33,32 +32,43 @@ use std::{
 ///
 /// The function expects a valid Git repository URL in the format "https://github.com/user/repo.git" or similar, where "user" is the username or organization and "repo" is the repository name.
 pub fn create_path_from_repo_name(git_url: &str) -> PathBuf {
-    let repository_name: String = git_url
-        .split('/')
-        .last()
-        .unwrap()
-        .split('.')
-        .next()
-        .unwrap()
-        .to_string();
+    let last_segment = git_url.trim_end_matches('/').rsplit('/').next().unwrap();
+    let repository_name = last_segment.strip_suffix(".git").unwrap_or(last_segment);
 
     let mut path: PathBuf = env::current_dir().unwrap();
     path.push(repository_name);
     path
 }
 
-/// Downloads a Git repository from the provided URL to the current directory.
+/// Downloads a Git repository from the provided URL to the current directory, optionally
+/// pinned to a tag/branch/commit and scoped to a subdirectory.
 ///
-/// This function clones a Git repository from the specified URL to the current working directory
-/// if the repository does not already exist there.
+/// This function clones a Git repository from the specified URL to the current working
+/// directory if the repository does not already exist there, or fetches and checks out the
+/// latest changes in place if it does (see [`update_existing_checkout`]), so re-analyzing a
+/// target picks up new commits instead of silently reusing a stale checkout.
+///
+/// Authentication for private repositories is handled by `git` itself: an `ssh://`/`git@`
+/// `git_url` goes through the local SSH agent, and an `https://` URL with a token embedded as
+/// userinfo (`https://TOKEN@host/repo.git`) is passed straight through as a credential.
 ///
 /// # Arguments
 ///
 /// * `git_url` - The URL of the Git repository to be downloaded.
+/// * `reference` - A branch, tag, or commit SHA to check out, if the caller wants something
+///   other than the default branch.
+/// * `subdir` - A subdirectory of the repository to point analysis at, if the caller wants to
+///   audit a single workspace member rather than the whole repository.
 ///
 /// # Returns
 ///
-/// A `PathBuf` representing the path to the downloaded or existing repository.
+/// A `PathBuf` representing the path to `subdir` within the downloaded (or already-existing)
+/// repository, or to the repository root if `subdir` is `None`.
+///
+/// # Errors
+///
+/// Returns an error if `git` is not installed, or the clone/fetch/checkout fails (e.g.
+/// `reference` does not name a branch, tag, or commit on the remote).
 ///
 /// # Example
 ///
@@ -67,30 +77,72 @@ pub fn create_path_from_repo_name(git_url: &str) -> PathBuf {
 /// use std::env;
 ///
 /// let git_url = "https://github.com/hknio/rca.git";
-/// let path = download_from_git(git_url);
+/// let path = download_from_git(git_url, None, None).unwrap();
 /// assert_eq!(path.to_str().unwrap(), env::current_dir().unwrap().to_str().unwrap().to_string() + "/rca");
 /// ```
-///
-/// # Note
-///
-/// If the repository already exists in the current directory, the function will not perform a download
-/// and will instead print "Repository already exists."
-pub fn download_from_git(git_url: &str) -> PathBuf {
-    let path = create_path_from_repo_name(&git_url);
+pub fn download_from_git(
+    git_url: &str,
+    reference: Option<&str>,
+    subdir: Option<&str>,
+) -> Result<PathBuf, Error> {
+    let path = create_path_from_repo_name(git_url);
 
-    if !path.exists() {
-        let output: std::process::Output = Command::new("git")
-            .args(["clone", git_url])
-            .output()
-            .expect("Failed to execute process");
+    if path.exists() {
+        update_existing_checkout(&path, reference)?;
+    } else {
+        clone_shallow(git_url, &path, reference)?;
+    }
 
-        println!("Status: {}", output.status);
+    Ok(match subdir {
+        Some(subdir) => path.join(subdir),
+        None => path,
+    })
+}
 
-        io::stdout().write_all(&output.stdout).unwrap();
-        io::stderr().write_all(&output.stderr).unwrap();
-    } else {
-        println!("Repository already exists.");
+/// Performs a shallow (`--depth 1`) clone of `git_url` into `path`, then checks out `reference`
+/// if one is given.
+fn clone_shallow(git_url: &str, path: &Path, reference: Option<&str>) -> Result<(), Error> {
+    Cmd::new("git")
+        .args(["clone", "--depth", "1", git_url])
+        .arg(path.to_string_lossy().into_owned())
+        .run()?;
+
+    if let Some(reference) = reference {
+        checkout_reference(path, reference)?;
     }
 
-    path
+    Ok(())
+}
+
+/// Brings an already-downloaded repository at `path` up to date instead of leaving it to go
+/// stale across repeated analysis runs: checks out `reference` again if one is given (picking
+/// up new commits on a branch, or switching to a different tag/commit), otherwise fast-forwards
+/// the current branch.
+fn update_existing_checkout(path: &Path, reference: Option<&str>) -> Result<(), Error> {
+    println!("Repository already exists, fetching latest changes...");
+
+    match reference {
+        Some(reference) => checkout_reference(path, reference),
+        None => Cmd::new("git")
+            .current_dir(path)
+            .args(["pull", "--ff-only"])
+            .run(),
+    }
+}
+
+/// Fetches `reference` (a branch, tag, or commit SHA) directly and checks it out.
+///
+/// Fetching the reference itself, rather than `git clone --branch`/`git checkout` against
+/// already-fetched history, works uniformly across branches, tags, *and* commit SHAs, and keeps
+/// the fetch shallow even when `path` was already a full (non-shallow) clone.
+fn checkout_reference(path: &Path, reference: &str) -> Result<(), Error> {
+    Cmd::new("git")
+        .current_dir(path)
+        .args(["fetch", "--depth", "1", "origin", reference])
+        .run()?;
+
+    Cmd::new("git")
+        .current_dir(path)
+        .args(["checkout", "FETCH_HEAD"])
+        .run()
 }
@@ -0,0 +1,64 @@
+use thiserror::Error as ThisError;
+
+/// The crate-wide error type.
+///
+/// Every analysis entry point (`issues::find_*`, `quality::search_*`) and the [`crate::command::Cmd`]
+/// runner return this instead of panicking (except `quality::search_sloc_number_json` and
+/// `quality::search_code_coverage_json`, which return a boxed error), so a missing toolchain
+/// binary or a failed `cargo check` surfaces as an error a caller (or a future CI mode) can inspect, rather than crashing the process
+/// or being silently swallowed.
+#[derive(Debug, ThisError)]
+pub enum Error {
+    /// The child process could not be spawned at all (e.g. the binary is not on `PATH`).
+    #[error("failed to execute `{command}`: {source}")]
+    Spawn {
+        command: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The child process ran but exited with a non-zero status.
+    #[error("`{command}` exited with status {status}")]
+    NonZeroExit {
+        command: String,
+        status: std::process::ExitStatus,
+    },
+
+    /// A required tool is not installed or not on `PATH`. `install_hint`, if known (see
+    /// [`crate::command::install_hint`]), is surfaced alongside the tool name so the error
+    /// tells the user how to fix it, not just what's wrong.
+    #[error(
+        "required tool `{tool}` is not installed{}",
+        install_hint
+            .as_deref()
+            .map(|hint| format!(" (install with `{hint}`)"))
+            .unwrap_or_default()
+    )]
+    MissingTool {
+        tool: String,
+        install_hint: Option<String>,
+    },
+
+    /// The target path is neither a valid local path nor a remote repository URL.
+    #[error(transparent)]
+    TargetPath(#[from] crate::target::TargetPathError),
+
+    /// A `--checks`/config entry did not match any known check name.
+    #[error("unknown check `{0}`")]
+    UnknownCheck(String),
+
+    /// A check ran successfully but its report could not be generated or exported.
+    #[error("failed to generate report: {0}")]
+    Report(String),
+}
+
+impl Error {
+    /// The exit code of the underlying child process, if this error was caused by one
+    /// that ran to completion with a non-zero status.
+    pub fn exit_code(&self) -> Option<i32> {
+        match self {
+            Error::NonZeroExit { status, .. } => status.code(),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::command;
+use crate::{command::Cmd, error::Error, quality, report};
 use ansi_term::Colour::Green;
+use serde::{Deserialize, Serialize};
 use std::ffi::OsStr;
 
 /// Represents different kinds of code issues that can be searched for.
@@ -7,6 +8,7 @@ use std::ffi::OsStr;
 /// This enum defines various kinds of code issues that can be searched for within a codebase,
 /// including formatting issues, compilation warnings and errors, outdated and vulnerable dependencies,
 /// integer arithmetic, and error handling practices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IssueKind {
     Formatting,
     CompilationWarning,
@@ -34,15 +36,31 @@ pub enum IssueKind {
 /// use rca::issues::search;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// search(path);
+/// let _ = search(path);
 /// ```
-pub fn search(path: &OsStr) {
-    find_compilation_errors(path);
-    find_formatting_issues(path);
-    find_outdated_dependencies(path);
-    find_vulnerable_dependencies(path);
-    find_integer_arithmetics(path);
-    find_unwrap_expect(path);
+pub fn search(path: &OsStr) -> Result<(), Error> {
+    let checks: [fn(&OsStr) -> Result<(), Error>; 6] = [
+        find_compilation_errors,
+        find_formatting_issues,
+        find_outdated_dependencies,
+        find_vulnerable_dependencies,
+        find_integer_arithmetics,
+        find_unwrap_expect,
+    ];
+
+    let mut last_error = None;
+
+    for check in checks {
+        if let Err(error) = check(path) {
+            eprintln!("{error}");
+            last_error = Some(error);
+        }
+    }
+
+    match last_error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
 }
 
 /// Searches for compilation errors and warnings within a given path.
@@ -60,14 +78,14 @@ pub fn search(path: &OsStr) {
 /// use rca::issues::find_compilation_errors;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// find_compilation_errors(path);
+/// let _ = find_compilation_errors(path);
 /// ```
-pub fn find_compilation_errors(path: &OsStr) {
+pub fn find_compilation_errors(path: &OsStr) -> Result<(), Error> {
     println!(
         "{}",
         Green.bold().paint("\n# Compilation Errors & Warnings")
     );
-    command::execute_command("cargo", path, &["check"], false);
+    Cmd::new("cargo").current_dir(path).args(["check"]).run()
 }
 
 /// Searches for formatting issues within a given path.
@@ -85,11 +103,15 @@ pub fn find_compilation_errors(path: &OsStr) {
 /// use rca::issues::find_formatting_issues;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// find_formatting_issues(path);
+/// let _ = find_formatting_issues(path);
 /// ```
-pub fn find_formatting_issues(path: &OsStr) {
+pub fn find_formatting_issues(path: &OsStr) -> Result<(), Error> {
     println!("\n{}", Green.bold().paint("\n# Formatting Issues"));
-    command::execute_command("cargo", path, &["fmt", "--check"], false);
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args(["fmt", "--check"])
+        .requires("cargo-fmt")
+        .run()
 }
 
 /// Searches for outdated dependencies within a given path.
@@ -107,11 +129,15 @@ pub fn find_formatting_issues(path: &OsStr) {
 /// use rca::issues::find_outdated_dependencies;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// find_outdated_dependencies(path);
+/// let _ = find_outdated_dependencies(path);
 /// ```
-pub fn find_outdated_dependencies(path: &OsStr) {
+pub fn find_outdated_dependencies(path: &OsStr) -> Result<(), Error> {
     println!("\n{}", Green.bold().paint("\n# Outdated Dependencies"));
-    command::execute_command("cargo", path, &["outdated"], false);
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args(["outdated"])
+        .requires("cargo-outdated")
+        .run()
 }
 
 /// Searches for vulnerable dependencies within a given path.
@@ -129,11 +155,15 @@ pub fn find_outdated_dependencies(path: &OsStr) {
 /// use rca::issues::find_vulnerable_dependencies;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// find_vulnerable_dependencies(path);
+/// let _ = find_vulnerable_dependencies(path);
 /// ```
-pub fn find_vulnerable_dependencies(path: &OsStr) {
+pub fn find_vulnerable_dependencies(path: &OsStr) -> Result<(), Error> {
     println!("\n{}", Green.bold().paint("\n# Vulnerable Dependencies"));
-    command::execute_command("cargo", path, &["audit"], false);
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args(["audit"])
+        .requires("cargo-audit")
+        .run()
 }
 
 /// Searches for integer arithmetic issues within a given path.
@@ -151,23 +181,384 @@ pub fn find_vulnerable_dependencies(path: &OsStr) {
 /// use rca::issues::find_integer_arithmetics;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// find_integer_arithmetics(path);
+/// let _ = find_integer_arithmetics(path);
 /// ```
-pub fn find_integer_arithmetics(path: &OsStr) {
+pub fn find_integer_arithmetics(path: &OsStr) -> Result<(), Error> {
     println!("\n{}", Green.bold().paint("\n# Integer Arithmetics"));
-    command::execute_command(
-        "cargo",
-        path,
-        &[
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args([
             "clippy",
             "--",
             "-A",
             "clippy::all",
             "-D",
             "clippy::arithmetic_side_effects",
-        ],
-        true,
-    );
+        ])
+        .requires("cargo-clippy")
+        .run()
+}
+
+/// A single line of the JSON stream emitted by `cargo` with `--message-format=json`.
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<CompilerMessage>,
+}
+
+/// The `rustc` diagnostic carried by a `"compiler-message"` [`CargoMessage`].
+#[derive(Deserialize)]
+struct CompilerMessage {
+    message: String,
+    code: Option<CompilerCode>,
+    level: String,
+    spans: Vec<CompilerSpan>,
+}
+
+#[derive(Deserialize)]
+struct CompilerCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct CompilerSpan {
+    file_name: String,
+    line_start: usize,
+    column_start: usize,
+    is_primary: bool,
+}
+
+/// Parses the newline-delimited JSON emitted by `cargo check --message-format=json` or
+/// `cargo clippy --message-format=json` into structured [`report::Issue`] records, keeping
+/// only the primary span of each compiler message and classifying it into an [`IssueKind`].
+fn parse_compiler_messages(json_output: &str) -> Vec<report::Issue> {
+    json_output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CargoMessage>(line).ok())
+        .filter(|entry| entry.reason == "compiler-message")
+        .filter_map(|entry| entry.message)
+        .map(|message| {
+            let span = message.spans.iter().find(|span| span.is_primary);
+            let lint = message.code.map(|code| code.code);
+
+            let kind = match lint.as_deref() {
+                Some("clippy::arithmetic_side_effects") => IssueKind::OutOfBound,
+                Some("clippy::unwrap_used") | Some("clippy::expect_used") => {
+                    IssueKind::ErrorHandling
+                }
+                _ if message.level == "error" => IssueKind::CompilationError,
+                _ => IssueKind::CompilationWarning,
+            };
+
+            report::Issue {
+                kind,
+                file: span.map(|span| span.file_name.clone()),
+                line: span.map(|span| span.line_start),
+                column: span.map(|span| span.column_start),
+                name: lint.unwrap_or_default(),
+                severity: message.level,
+                message: message.message,
+            }
+        })
+        .collect()
+}
+
+/// Runs `cargo check` with JSON diagnostics and returns structured [`report::Issue`] records
+/// instead of printing raw compiler output.
+///
+/// # Errors
+///
+/// Returns an error if `cargo` could not be spawned.
+pub fn find_compilation_errors_json(path: &OsStr) -> Result<Vec<report::Issue>, Error> {
+    let captured = Cmd::new("cargo")
+        .current_dir(path)
+        .args(["check", "--message-format=json"])
+        .capture()?;
+
+    Ok(parse_compiler_messages(&captured.stdout))
+}
+
+/// Runs `cargo clippy` restricted to `clippy::arithmetic_side_effects` with JSON diagnostics
+/// and returns structured [`report::Issue`] records.
+///
+/// # Errors
+///
+/// Returns an error if `cargo` could not be spawned.
+pub fn find_integer_arithmetics_json(path: &OsStr) -> Result<Vec<report::Issue>, Error> {
+    let captured = Cmd::new("cargo")
+        .current_dir(path)
+        .args([
+            "clippy",
+            "--message-format=json",
+            "--",
+            "-A",
+            "clippy::all",
+            "-D",
+            "clippy::arithmetic_side_effects",
+        ])
+        .requires("cargo-clippy")
+        .capture()?;
+
+    Ok(parse_compiler_messages(&captured.stdout))
+}
+
+/// Runs `cargo clippy` restricted to `clippy::unwrap_used`/`clippy::expect_used` with JSON
+/// diagnostics and returns structured [`report::Issue`] records.
+///
+/// # Errors
+///
+/// Returns an error if `cargo` could not be spawned.
+pub fn find_unwrap_expect_json(path: &OsStr) -> Result<Vec<report::Issue>, Error> {
+    let captured = Cmd::new("cargo")
+        .current_dir(path)
+        .args([
+            "clippy",
+            "--message-format=json",
+            "--",
+            "-A",
+            "clippy::all",
+            "-D",
+            "clippy::unwrap_used",
+            "-D",
+            "clippy::expect_used",
+        ])
+        .requires("cargo-clippy")
+        .capture()?;
+
+    Ok(parse_compiler_messages(&captured.stdout))
+}
+
+#[derive(Deserialize)]
+struct AuditReport {
+    vulnerabilities: AuditVulnerabilities,
+}
+
+#[derive(Deserialize)]
+struct AuditVulnerabilities {
+    list: Vec<AuditVulnerability>,
+}
+
+#[derive(Deserialize)]
+struct AuditVulnerability {
+    advisory: AuditAdvisory,
+    package: AuditPackage,
+}
+
+#[derive(Deserialize)]
+struct AuditAdvisory {
+    id: String,
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct AuditPackage {
+    name: String,
+}
+
+/// Runs `cargo audit --json` and returns each reported vulnerability as a structured
+/// [`report::Issue`] with kind [`IssueKind::VulnerableDependency`].
+///
+/// # Errors
+///
+/// Returns an error if `cargo-audit` could not be spawned.
+pub fn find_vulnerable_dependencies_json(path: &OsStr) -> Result<Vec<report::Issue>, Error> {
+    let captured = Cmd::new("cargo")
+        .current_dir(path)
+        .args(["audit", "--json"])
+        .requires("cargo-audit")
+        .capture()?;
+
+    let audit: AuditReport = serde_json::from_str(&captured.stdout).unwrap_or(AuditReport {
+        vulnerabilities: AuditVulnerabilities { list: Vec::new() },
+    });
+
+    Ok(audit
+        .vulnerabilities
+        .list
+        .into_iter()
+        .map(|vulnerability| report::Issue {
+            kind: IssueKind::VulnerableDependency,
+            file: None,
+            line: None,
+            column: None,
+            name: vulnerability.package.name,
+            severity: "error".to_string(),
+            message: format!(
+                "{}: {}",
+                vulnerability.advisory.id, vulnerability.advisory.title
+            ),
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct OutdatedReport {
+    dependencies: Vec<OutdatedDependencyEntry>,
+}
+
+#[derive(Deserialize)]
+struct OutdatedDependencyEntry {
+    name: String,
+    project: String,
+    latest: String,
+}
+
+/// Runs `cargo outdated --format json` and returns each outdated dependency as a structured
+/// [`report::Issue`] with kind [`IssueKind::OutdatedDependency`].
+///
+/// # Errors
+///
+/// Returns an error if `cargo-outdated` could not be spawned.
+pub fn find_outdated_dependencies_json(path: &OsStr) -> Result<Vec<report::Issue>, Error> {
+    let captured = Cmd::new("cargo")
+        .current_dir(path)
+        .args(["outdated", "--format", "json"])
+        .requires("cargo-outdated")
+        .capture()?;
+
+    let outdated: OutdatedReport =
+        serde_json::from_str(&captured.stdout).unwrap_or(OutdatedReport {
+            dependencies: Vec::new(),
+        });
+
+    Ok(outdated
+        .dependencies
+        .into_iter()
+        .map(|dependency| report::Issue {
+            kind: IssueKind::OutdatedDependency,
+            file: None,
+            line: None,
+            column: None,
+            name: dependency.name,
+            severity: "warning".to_string(),
+            message: format!("{} -> {}", dependency.project, dependency.latest),
+        })
+        .collect())
+}
+
+/// Runs every structured check (compilation diagnostics, arithmetic and unwrap/expect lint
+/// violations, outdated dependencies, and vulnerable dependencies) and collects their issues
+/// into one list, shared by [`generate_issues_report`] and [`generate_sonar_issues_report`] so
+/// the two report generators can't drift as checks are added or removed.
+///
+/// # Errors
+///
+/// Returns an error if any underlying check fails to run.
+fn collect_all_issues(path: &OsStr) -> Result<Vec<report::Issue>, Box<dyn std::error::Error>> {
+    let mut issues = find_compilation_errors_json(path)?;
+    issues.extend(find_integer_arithmetics_json(path)?);
+    issues.extend(find_unwrap_expect_json(path)?);
+    issues.extend(find_outdated_dependencies_json(path)?);
+    issues.extend(find_vulnerable_dependencies_json(path)?);
+    Ok(issues)
+}
+
+/// Generates a structured issues report covering compilation diagnostics, arithmetic and
+/// unwrap/expect lint violations, outdated dependencies, and vulnerable dependencies, and
+/// writes it to `issues_report.json`.
+///
+/// # Errors
+///
+/// Returns an error if any underlying check fails to run, or if writing the report fails.
+pub fn generate_issues_report(path: &OsStr) -> Result<(), Box<dyn std::error::Error>> {
+    let issues = collect_all_issues(path)?;
+
+    let issues_report = report::Issues { issues };
+    let issues_report = serde_json::to_string_pretty(&issues_report)?;
+
+    quality::write_json_to_file("issues_report".to_string(), issues_report)?;
+
+    Ok(())
+}
+
+/// SonarQube/SonarCloud's "generic issue import" format, as consumed via
+/// `sonar.externalIssuesReportPaths`.
+#[derive(Debug, Serialize)]
+struct SonarIssuesReport {
+    issues: Vec<SonarIssue>,
+}
+
+#[derive(Debug, Serialize)]
+struct SonarIssue {
+    #[serde(rename = "engineId")]
+    engine_id: String,
+    #[serde(rename = "ruleId")]
+    rule_id: String,
+    severity: String,
+    #[serde(rename = "type")]
+    issue_type: String,
+    #[serde(rename = "primaryLocation")]
+    primary_location: SonarLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct SonarLocation {
+    message: String,
+    #[serde(rename = "filePath")]
+    file_path: String,
+    #[serde(rename = "textRange")]
+    text_range: Option<SonarTextRange>,
+}
+
+#[derive(Debug, Serialize)]
+struct SonarTextRange {
+    #[serde(rename = "startLine")]
+    start_line: usize,
+}
+
+/// Maps a [`report::Issue`]'s severity string (`"error"`/`"warning"`) to the closest
+/// SonarQube generic-issue severity.
+fn sonar_severity(severity: &str) -> &'static str {
+    match severity {
+        "error" => "MAJOR",
+        _ => "MINOR",
+    }
+}
+
+/// Maps an [`IssueKind`] to the SonarQube generic-issue type it's closest to.
+fn sonar_type(kind: IssueKind) -> &'static str {
+    match kind {
+        IssueKind::VulnerableDependency => "VULNERABILITY",
+        IssueKind::CompilationError => "BUG",
+        _ => "CODE_SMELL",
+    }
+}
+
+/// Generates a SonarQube/SonarCloud "generic issue import" report covering the same checks as
+/// [`generate_issues_report`], and writes it to `sonar_issues_report.json` for upload via
+/// `sonar.externalIssuesReportPaths`.
+///
+/// Issues without a known file (e.g. dependency advisories) are omitted, since SonarQube's
+/// generic issue format requires a `filePath` on every entry.
+///
+/// # Errors
+///
+/// Returns an error if any underlying check fails to run, or if writing the report fails.
+pub fn generate_sonar_issues_report(path: &OsStr) -> Result<(), Box<dyn std::error::Error>> {
+    let issues = collect_all_issues(path)?
+        .into_iter()
+        .filter_map(|issue| {
+            let file_path = issue.file?;
+            Some(SonarIssue {
+                engine_id: "rca".to_string(),
+                rule_id: issue.name,
+                severity: sonar_severity(&issue.severity).to_string(),
+                issue_type: sonar_type(issue.kind).to_string(),
+                primary_location: SonarLocation {
+                    message: issue.message,
+                    file_path,
+                    text_range: issue.line.map(|start_line| SonarTextRange { start_line }),
+                },
+            })
+        })
+        .collect();
+
+    let sonar_report = SonarIssuesReport { issues };
+    let sonar_report = serde_json::to_string_pretty(&sonar_report)?;
+
+    quality::write_json_to_file("sonar_issues_report".to_string(), sonar_report)?;
+
+    Ok(())
 }
 
 /// Searches for error handling practices and unwrapping within a given path.
@@ -185,17 +576,16 @@ pub fn find_integer_arithmetics(path: &OsStr) {
 /// use rca::issues::find_unwrap_expect;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// find_unwrap_expect(path);
+/// let _ = find_unwrap_expect(path);
 /// ```
-pub fn find_unwrap_expect(path: &OsStr) {
+pub fn find_unwrap_expect(path: &OsStr) -> Result<(), Error> {
     println!(
         "\n{}",
         Green.bold().paint("\n# Error Handling & Unwrapping")
     );
-    command::execute_command(
-        "cargo",
-        path,
-        &[
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args([
             "clippy",
             "--",
             "-A",
@@ -204,7 +594,7 @@ pub fn find_unwrap_expect(path: &OsStr) {
             "clippy::unwrap_used",
             "-D",
             "clippy::expect_used",
-        ],
-        true,
-    );
+        ])
+        .requires("cargo-clippy")
+        .run()
 }
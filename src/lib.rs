@@ -1,13 +1,21 @@
 /// Provides functionality for executing shell commands.
-pub mod cmd;
 pub mod command;
 
+/// Non-interactive, config-driven check selection for CI.
+pub mod config;
+
+/// Exports `report::Coverage` into standard interchange formats (LCOV, Cobertura, ...).
+pub mod coverage;
+
 /// Manages Rust toolchain dependencies and system binaries.
 pub mod dependencies;
 
 /// Handles the downloading of Git repositories.
 pub mod download;
 
+/// The crate-wide error type.
+pub mod error;
+
 /// Identifies and reports issues in Rust code.
 pub mod issues;
 
@@ -1,10 +1,14 @@
-use crate::{
-    command,
-    report::{self, Sloc},
-};
+use crate::{command::Cmd, coverage, error::Error, report};
 use ansi_term::Colour::Green;
-use serde_json::{to_string_pretty, Map, Value};
-use std::{ffi::OsStr, fs::File, io::prelude::*};
+use serde::Deserialize;
+use serde_json::to_string_pretty;
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs::{self, File},
+    io::prelude::*,
+    path::Path,
+};
 
 /// Writes JSON content to a file.
 ///
@@ -90,13 +94,14 @@ pub fn generate_quality_report(path: &OsStr) -> Result<(), Box<dyn std::error::E
 /// use rca::quality::search;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// search(path);
+/// let _ = search(path);
 /// ```
 ///
-pub fn search(path: &OsStr) {
-    search_sloc_number(path);
-    search_dependency_graph(path);
-    search_code_coverage(path);
+pub fn search(path: &OsStr) -> Result<(), Error> {
+    search_sloc_number(path)?;
+    search_dependency_graph(path)?;
+    search_code_coverage(path)?;
+    Ok(())
 }
 
 /// Searches for the number of Source Lines of Code (SLOC) within a given path.
@@ -114,66 +119,58 @@ pub fn search(path: &OsStr) {
 /// use rca::quality::search_sloc_number;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// search_sloc_number(path);
+/// let _ = search_sloc_number(path);
 /// ```
-pub fn search_sloc_number(path: &OsStr) {
+pub fn search_sloc_number(path: &OsStr) -> Result<(), Error> {
     println!("{}", Green.bold().paint("\n# Number of SLOC:"));
-    if command::execute_command_no_path("tokei", &[path.to_str().unwrap()], false).is_err() {
-        println!("Error: tokei is not installed.");
-    }
-}
+    let captured = Cmd::new("tokei").arg(path.to_str().unwrap()).capture()?;
 
-/// Parses the JSON data to create a Source Lines of Code (SLOC) report.
-///
-/// This function takes a JSON object, parses it, and constructs a Source Lines of Code (SLOC) report
-/// structure from the data. The resulting report contains information about code lines, comments, and
-/// blank lines for various programming languages.
-///
-/// # Arguments
-///
-/// * `json` - A JSON object containing SLOC data.
-///
-/// # Returns
-///
-/// A `report::Sloc` structure representing the SLOC report.
-///
-fn parse_sloc_json(mut json: Map<String, Value>) -> report::Sloc {
-    let mut json_report: Sloc = report::Sloc {
-        language_info: Vec::new(),
-        code: 0,
-        comments: 0,
-        inaccurate: false,
-    };
+    print!("{}", captured.stdout);
+    eprint!("{}", captured.stderr);
+    Ok(())
+}
 
-    for (key, value) in json.iter_mut() {
-        let mut value: Map<String, Value> = value.as_object_mut().unwrap().clone();
-
-        value.remove("reports").unwrap();
-        value.remove("children").unwrap();
-
-        if key == &String::from("Total") {
-            json_report.code = value["code"].as_u64().unwrap() as usize;
-            json_report.comments = value["comments"].as_u64().unwrap() as usize;
-            json_report.inaccurate = value["inaccurate"].as_bool().unwrap();
-        } else {
-            let language_info = report::LanguageInfo {
-                language: key.clone(),
-                blanks: value["blanks"].as_u64().unwrap() as usize,
-                code: value["code"].as_u64().unwrap() as usize,
-                comments: value["comments"].as_u64().unwrap() as usize,
-            };
-
-            json_report.language_info.push(language_info);
+/// Translates `tokei`'s typed per-language statistics into the crate's own `report::Sloc`
+/// shape, summing each language's code/comment counts into the report-wide totals instead of
+/// relying on a `"Total"` entry the way the old JSON output did.
+fn sloc_from_languages(languages: &tokei::Languages) -> report::Sloc {
+    let mut language_info = Vec::new();
+    let mut code = 0;
+    let mut comments = 0;
+    let mut inaccurate = false;
+
+    for (language_type, language) in languages.iter() {
+        if language.is_empty() {
+            continue;
         }
+
+        code += language.code;
+        comments += language.comments;
+        inaccurate |= language.inaccurate;
+
+        language_info.push(report::LanguageInfo {
+            language: language_type.to_string(),
+            blanks: language.blanks,
+            code: language.code,
+            comments: language.comments,
+        });
     }
 
-    json_report
+    report::Sloc {
+        language_info,
+        code,
+        comments,
+        inaccurate,
+    }
 }
 
-/// Searches for the number of Source Lines of Code (SLOC) within a given path and returns the result as JSON.
+/// Computes Source Lines of Code (SLOC) statistics for `path` using the `tokei` crate directly,
+/// rather than shelling out to the `tokei` binary and parsing its JSON output.
 ///
-/// This function uses the `tokei` tool to count the number of Source Lines of Code (SLOC)
-/// within the specified path and returns the result as a JSON representation.
+/// Working against `tokei`'s typed `Languages`/`Language` API instead of hand-walking a
+/// `serde_json::Value` means a `tokei` version change can no longer make this function panic on
+/// an absent field, and it drops the external-binary dependency (and its "tokei is not
+/// installed" failure mode) entirely.
 ///
 /// # Arguments
 ///
@@ -181,23 +178,15 @@ fn parse_sloc_json(mut json: Map<String, Value>) -> report::Sloc {
 ///
 /// # Returns
 ///
-/// A `Result` containing the SLOC report in JSON format if successful, or an error if the `tokei`
-/// command is not installed or encounters other issues.
-///
+/// A `Result` containing the SLOC report. This function currently never returns an error:
+/// files that `tokei` cannot read are left out of the counts.
 pub fn search_sloc_number_json(path: &OsStr) -> Result<report::Sloc, Box<dyn std::error::Error>> {
     println!("{}", Green.bold().paint("# Generating SLOC Report..."));
 
-    if let Ok(output) = command::execute_command_no_path_return(
-        "tokei",
-        &[path.to_str().unwrap(), "-o", "json"],
-        false,
-    ) {
-        let json: Map<String, Value> = serde_json::from_str(&output[..]).unwrap();
-        let json_report: report::Sloc = parse_sloc_json(json);
-        Ok(json_report)
-    } else {
-        Err("Error: tokei is not installed.".into())
-    }
+    let mut languages = tokei::Languages::new();
+    languages.get_statistics(&[Path::new(path)], &[], &tokei::Config::default());
+
+    Ok(sloc_from_languages(&languages))
 }
 
 /// Searches for the dependency graph of a Rust project within a given path.
@@ -215,11 +204,11 @@ pub fn search_sloc_number_json(path: &OsStr) -> Result<report::Sloc, Box<dyn std
 /// use rca::quality::search_dependency_graph;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// search_dependency_graph(path);
+/// let _ = search_dependency_graph(path);
 /// ```
-pub fn search_dependency_graph(path: &OsStr) {
+pub fn search_dependency_graph(path: &OsStr) -> Result<(), Error> {
     println!("{}", Green.bold().paint("\n# Dependency graph:"));
-    command::execute_command("cargo", path, &["tree"], false);
+    Cmd::new("cargo").current_dir(path).args(["tree"]).run()
 }
 
 /// Searches for code coverage information within a given path.
@@ -237,129 +226,111 @@ pub fn search_dependency_graph(path: &OsStr) {
 /// use rca::quality::search_code_coverage;
 ///
 /// let path = std::ffi::OsStr::new("../");
-/// search_code_coverage(path);
+/// let _ = search_code_coverage(path);
 /// ```
-pub fn search_code_coverage(path: &OsStr) {
+pub fn search_code_coverage(path: &OsStr) -> Result<(), Error> {
     println!("{}", Green.bold().paint("\n# Code coverage:"));
-    command::execute_command("cargo", path, &["tarpaulin"], false);
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args(["tarpaulin"])
+        .requires("cargo-tarpaulin")
+        .run()
 }
 
-/// Parses the output of the Tarpaulin tool and extracts relevant coverage data.
-///
-/// This function takes the raw output from the Tarpaulin tool, splits it, and processes it
-/// to extract coverage information for each file. It returns a vector of strings, where each
-/// string represents coverage data for a file.
-///
-/// # Arguments
-///
-/// * `tarpaulin_output` - The raw output from the Tarpaulin tool.
-///
-/// # Returns
-///
-/// A vector of strings containing coverage data for each file.
-///
-pub fn parse_tarpaulin_output(tarpaulin_output: String) -> Vec<String> {
-    let mut output = tarpaulin_output.split("||").collect::<Vec<&str>>();
-    dbg!(&output);
-    output.remove(0);
-    output.remove(0);
-    output
-        .into_iter()
-        .map(|x| x.trim().to_string())
-        .collect::<Vec<String>>()
+/// Tarpaulin's own `tarpaulin-report.json` schema, as emitted by `cargo tarpaulin --out Json`.
+/// Only the fields `search_code_coverage_json` needs are modeled; anything else in the report
+/// is ignored by `serde`.
+#[derive(Debug, Deserialize)]
+struct TarpaulinReport {
+    files: Vec<TarpaulinFile>,
 }
 
-/// Parses the total code coverage data from Tarpaulin's output.
-///
-/// This function extracts and processes the total code coverage data from Tarpaulin's output.
-/// It calculates the coverage percentage, number of covered lines, and total lines of code.
-///
-/// # Arguments
-///
-/// * `total_coverage_data` - The total code coverage data as a string.
-///
-/// # Returns
-///
-/// A `report::Coverage` structure representing the total code coverage.
-///
-pub fn parse_total_coverage_data(total_coverage_data: String) -> report::Coverage {
-    let splitted_total = total_coverage_data
-        .split(",")
-        .map(|x| x.trim())
-        .collect::<Vec<&str>>();
-    let coverage_percentage = splitted_total[0].split("%").collect::<Vec<&str>>()[0]
-        .parse::<f64>()
-        .unwrap();
-    let num_lines = splitted_total[1].split(" ").collect::<Vec<&str>>()[0]
-        .split("/")
-        .collect::<Vec<&str>>();
-    let num_covered_lines = num_lines[0].parse::<u32>().unwrap();
-    let total_lines = num_lines[1].parse::<u32>().unwrap();
-
-    report::Coverage {
-        file_coverage: Vec::new(),
-        total_coverage_percentage: coverage_percentage,
-        num_covered_lines,
-        total_lines,
-    }
+/// A single file's entry in [`TarpaulinReport`].
+#[derive(Debug, Deserialize)]
+struct TarpaulinFile {
+    /// The file's path, split into segments (e.g. `["src", "lib.rs"]`).
+    path: Vec<String>,
+    /// The file's full source text, kept around so reporters can render an annotated view
+    /// without re-reading it from disk.
+    content: String,
+    covered: usize,
+    coverable: usize,
+    traces: Vec<report::Trace>,
 }
 
-/// Parses the uncovered lines for each file from Tarpaulin's output.
-///
-/// This function extracts and processes the uncovered lines data for each file from Tarpaulin's output.
-/// It returns a vector of `report::FileCoverage` structures, each representing a file and its uncovered lines.
-///
-/// # Arguments
-///
-/// * `files` - A vector of strings containing uncovered lines data for each file.
-///
-/// # Returns
-///
-/// A vector of `report::FileCoverage` structures representing each file's uncovered lines.
-///
-pub fn parse_each_file_uncovered_lines(files: Vec<String>) -> Vec<report::FileCoverage> {
-    let mut files_uncovered_lines = Vec::new();
-
-    for uncovered_data in files.iter() {
-        let splitted_uncovered = uncovered_data.split(":").collect::<Vec<&str>>();
-
-        let filename = splitted_uncovered[0];
-
-        let uncovered_lines = splitted_uncovered[1]
-            .split(",")
-            .map(|x| x.trim())
-            .collect::<Vec<&str>>();
-
-        let uncovered_lines = uncovered_lines
-            .into_iter()
-            .map(|x| {
-                if x.contains("-") {
-                    let splitted = x.split("-").collect::<Vec<&str>>();
-                    let start = splitted[0].parse::<u32>().unwrap();
-                    let end = splitted[1].parse::<u32>().unwrap();
-                    (start..=end).collect::<Vec<u32>>()
-                } else {
-                    vec![x.parse::<u32>().unwrap()]
-                }
+/// Turns a deserialized [`TarpaulinReport`] into the crate's own `report::Coverage` shape,
+/// separating line coverage (from [`report::CoverageStat::Line`]) from branch/condition
+/// coverage (from [`report::CoverageStat::Branch`]/[`report::CoverageStat::Condition`]).
+fn parse_tarpaulin_report(report: TarpaulinReport) -> report::Coverage {
+    let mut file_coverage = Vec::with_capacity(report.files.len());
+    let mut num_covered_lines = 0;
+    let mut total_lines = 0;
+    let mut num_covered_branches = 0;
+    let mut total_branches = 0;
+    let mut num_covered_conditions = 0;
+    let mut total_conditions = 0;
+
+    for file in report.files {
+        let uncovered_lines = file
+            .traces
+            .iter()
+            .filter_map(|trace| match &trace.stats {
+                report::CoverageStat::Line(hits) if *hits == 0 => Some(trace.line as u32),
+                _ => None,
             })
-            .flatten()
-            .collect::<Vec<u32>>();
+            .collect();
+
+        for trace in &file.traces {
+            match &trace.stats {
+                report::CoverageStat::Line(_) => {}
+                report::CoverageStat::Branch(branch) => {
+                    total_branches += 1;
+                    num_covered_branches += u32::from(branch.is_covered());
+                }
+                report::CoverageStat::Condition(conditions) => {
+                    total_conditions += conditions.len() as u32;
+                    num_covered_conditions += conditions
+                        .iter()
+                        .filter(|condition| condition.is_covered())
+                        .count() as u32;
+                }
+            }
+        }
 
-        files_uncovered_lines.push(report::FileCoverage {
-            name: filename.to_string(),
+        num_covered_lines += file.covered as u32;
+        total_lines += file.coverable as u32;
+
+        file_coverage.push(report::FileCoverage {
+            name: file.path.join("/"),
             uncovered_lines,
+            traces: file.traces,
+            content: file.content,
         });
     }
 
-    files_uncovered_lines
+    let total_coverage_percentage = if total_lines == 0 {
+        0.0
+    } else {
+        f64::from(num_covered_lines) / f64::from(total_lines) * 100.0
+    };
+
+    report::Coverage {
+        file_coverage,
+        total_coverage_percentage,
+        num_covered_lines,
+        total_lines,
+        total_branches,
+        num_covered_branches,
+        total_conditions,
+        num_covered_conditions,
+    }
 }
 
 /// Searches for code coverage information using the Tarpaulin tool within a given path.
 ///
-/// This function runs the `cargo tarpaulin` command with the specified path to generate
-/// and display code coverage information for a Rust project. It returns a `Result` containing
-/// the code coverage report if successful or an error if Tarpaulin is not installed or if
-/// there are other issues.
+/// This function runs `cargo tarpaulin --out Json`, which writes `tarpaulin-report.json` into
+/// `path`, and deserializes that report directly instead of scraping Tarpaulin's terminal
+/// output, so line, branch, and condition coverage survive intact.
 ///
 /// # Arguments
 ///
@@ -378,25 +349,182 @@ pub fn search_code_coverage_json(
         Green.bold().paint("# Generating Code Coverage Report...")
     );
 
-    if let Ok(tarpaulin_output) =
-        command::execute_command_return("cargo", path, &["tarpaulin"], false)
-    {
-        let parsed_output = parse_tarpaulin_output(tarpaulin_output);
+    Cmd::new("cargo")
+        .current_dir(path)
+        .args(["tarpaulin", "--out", "Json"])
+        .requires("cargo-tarpaulin")
+        .capture()?;
 
-        let coverege_index = parsed_output
-            .iter()
-            .position(|x| x.contains("Total Lines"))
-            .unwrap();
+    let report_json = fs::read_to_string(Path::new(path).join("tarpaulin-report.json"))?;
+    coverage_from_tarpaulin_json(&report_json)
+}
 
-        let uncovered = Vec::from(&parsed_output[..coverege_index]);
-        let total = parsed_output[parsed_output.len() - 1].to_string();
+/// Parses the contents of a Tarpaulin `tarpaulin-report.json` file into the crate's own
+/// `report::Coverage` shape.
+pub fn coverage_from_tarpaulin_json(
+    json: &str,
+) -> Result<report::Coverage, Box<dyn std::error::Error>> {
+    let report: TarpaulinReport = serde_json::from_str(json)?;
+    Ok(parse_tarpaulin_report(report))
+}
+
+/// A single file's accumulated state while merging several coverage runs: its source text
+/// (identical across runs, so the first run's copy is kept) and its traces, keyed by line so
+/// traces for the same line from different runs can be folded together.
+struct MergedFile {
+    content: String,
+    traces_by_line: HashMap<usize, report::Trace>,
+}
+
+/// Merges coverage from several Tarpaulin runs (e.g. one per feature-flag combination or
+/// target) into a single authoritative report, so that running a project under multiple
+/// configurations doesn't leave each run's coverage shadowing the others.
+///
+/// A line is covered if it was covered (hit count > 0) in *any* run: hit counts are summed
+/// across runs, so the merged uncovered-line set is the intersection of the per-run uncovered
+/// sets. Branch and condition `LogicState`s are OR-ed field-wise, so a branch/condition counts
+/// as taken if any run took it.
+pub fn merge_coverage(reports: Vec<report::Coverage>) -> report::Coverage {
+    let mut files: HashMap<String, MergedFile> = HashMap::new();
+    let mut file_order: Vec<String> = Vec::new();
+
+    for coverage in reports {
+        for file in coverage.file_coverage {
+            let merged_file = files.entry(file.name.clone()).or_insert_with(|| {
+                file_order.push(file.name.clone());
+                MergedFile {
+                    content: file.content,
+                    traces_by_line: HashMap::new(),
+                }
+            });
+
+            for trace in file.traces {
+                merged_file
+                    .traces_by_line
+                    .entry(trace.line)
+                    .and_modify(|existing| merge_trace(existing, &trace))
+                    .or_insert(trace);
+            }
+        }
+    }
+
+    let mut file_coverage = Vec::with_capacity(file_order.len());
+    let mut num_covered_lines = 0;
+    let mut total_lines = 0;
+    let mut total_branches = 0;
+    let mut num_covered_branches = 0;
+    let mut total_conditions = 0;
+    let mut num_covered_conditions = 0;
+
+    for name in file_order {
+        let merged_file = files
+            .remove(&name)
+            .expect("every name in file_order has a matching entry in files");
+
+        let mut traces: Vec<report::Trace> = merged_file.traces_by_line.into_values().collect();
+        traces.sort_by_key(|trace| trace.line);
+
+        let uncovered_lines = traces
+            .iter()
+            .filter_map(|trace| match &trace.stats {
+                report::CoverageStat::Line(hits) if *hits == 0 => Some(trace.line as u32),
+                _ => None,
+            })
+            .collect();
 
-        let mut code_coverage = parse_total_coverage_data(total);
+        for trace in &traces {
+            match &trace.stats {
+                report::CoverageStat::Line(hits) => {
+                    total_lines += 1;
+                    num_covered_lines += u32::from(*hits > 0);
+                }
+                report::CoverageStat::Branch(branch) => {
+                    total_branches += 1;
+                    num_covered_branches += u32::from(branch.is_covered());
+                }
+                report::CoverageStat::Condition(conditions) => {
+                    total_conditions += conditions.len() as u32;
+                    num_covered_conditions += conditions
+                        .iter()
+                        .filter(|condition| condition.is_covered())
+                        .count() as u32;
+                }
+            }
+        }
 
-        code_coverage.file_coverage = parse_each_file_uncovered_lines(uncovered);
+        file_coverage.push(report::FileCoverage {
+            name,
+            uncovered_lines,
+            traces,
+            content: merged_file.content,
+        });
+    }
 
-        Ok(code_coverage)
+    let total_coverage_percentage = if total_lines == 0 {
+        0.0
     } else {
-        Err("Error: tarpaulin is not installed.".into())
+        f64::from(num_covered_lines) / f64::from(total_lines) * 100.0
+    };
+
+    report::Coverage {
+        file_coverage,
+        total_coverage_percentage,
+        num_covered_lines,
+        total_lines,
+        total_branches,
+        num_covered_branches,
+        total_conditions,
+        num_covered_conditions,
+    }
+}
+
+/// Folds `incoming`'s stats into `existing`, for two traces of the same file and line from
+/// different runs: hit counts are summed, and `LogicState`s are OR-ed field-wise.
+fn merge_trace(existing: &mut report::Trace, incoming: &report::Trace) {
+    match (&mut existing.stats, &incoming.stats) {
+        (report::CoverageStat::Line(existing_hits), report::CoverageStat::Line(incoming_hits)) => {
+            *existing_hits += incoming_hits;
+        }
+        (
+            report::CoverageStat::Branch(existing_state),
+            report::CoverageStat::Branch(incoming_state),
+        ) => {
+            existing_state.been_true |= incoming_state.been_true;
+            existing_state.been_false |= incoming_state.been_false;
+        }
+        (
+            report::CoverageStat::Condition(existing_states),
+            report::CoverageStat::Condition(incoming_states),
+        ) => {
+            for (existing_state, incoming_state) in existing_states.iter_mut().zip(incoming_states)
+            {
+                existing_state.been_true |= incoming_state.been_true;
+                existing_state.been_false |= incoming_state.been_false;
+            }
+        }
+        _ => {}
     }
 }
+
+/// Runs the Tarpaulin-backed coverage check and writes its report via the [`coverage::CoverageReporter`]
+/// named by `format` (`json`, `lcov`, `cobertura`, `html`, or `sonar`).
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownCheck`] if `format` does not name a known reporter, or
+/// [`Error::Report`] if Tarpaulin fails to run or the report cannot be written.
+pub fn export_coverage(path: &OsStr, format: &str) -> Result<(), Error> {
+    let code_coverage =
+        search_code_coverage_json(path).map_err(|error| Error::Report(error.to_string()))?;
+
+    let reporter =
+        coverage::reporter_for(format).ok_or_else(|| Error::UnknownCheck(format.to_string()))?;
+
+    let path = reporter
+        .write(&code_coverage, "coverage_report")
+        .map_err(|error| Error::Report(error.to_string()))?;
+
+    println!("{}", Green.bold().paint(format!("-> Wrote {path}")));
+
+    Ok(())
+}
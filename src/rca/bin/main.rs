@@ -1,35 +1,133 @@
 use ansi_term::Colour::{Green, Yellow};
-use rca::{dependencies, download, issues, quality, target};
-use std::{env, error::Error, io::Write};
+use rca::{
+    config::{self, Config},
+    coverage, dependencies, download, issues, quality, target,
+};
+use std::{env, error::Error, io::Write, path::Path};
 
-/// Downloads a Git repository specified as a command-line argument.
+/// Resolves a Git repository specified as a command-line argument, downloading it if needed.
 ///
-/// This function downloads a Git repository from the provided URL and returns the path to the downloaded repository.
+/// `target` may be a local path, a remote repository URL, or a remote URL carrying a
+/// `#reference[:subdir]` fragment (see [`target::TargetPath::new`]) to pin the checkout to a
+/// tag/branch and/or scope analysis to a single subdirectory.
+///
+/// # Arguments
+///
+/// * `target` - The local path or remote repository URL to analyze.
 ///
 /// # Returns
 ///
-/// A `std::path::PathBuf` representing the path to the downloaded repository.
-fn download_repository() -> std::path::PathBuf {
-    let target: String = env::args().skip(1).take(1).collect::<String>();
-    let target_path: target::TargetPath = target::TargetPath::new(target.clone()).unwrap();
-    if target_path.is_local() {
-        match target_path {
-            target::TargetPath::Path(path) => path,
-            _ => panic!("Fatal Error: TargetPath is not a local path"),
-        }
+/// A `std::path::PathBuf` representing the path to analyze.
+///
+/// # Errors
+///
+/// Returns an error if `target` is neither a valid local path nor a remote repository URL, or
+/// if downloading the remote repository fails.
+fn download_repository(target: &str) -> Result<std::path::PathBuf, Box<dyn Error>> {
+    match target::TargetPath::new(target.to_string())? {
+        target::TargetPath::Path(path) => Ok(path),
+        target::TargetPath::RemoteRepository {
+            url,
+            reference,
+            subdir,
+        } => Ok(download::download_from_git(
+            &url,
+            reference.as_deref(),
+            subdir.as_deref(),
+        )?),
+    }
+}
+
+/// Parses a `Config` for non-interactive (CI) mode out of `--checks a,b,c` or `--config
+/// rca.toml`, if either flag is present. A `--format lcov|cobertura|json|html|sonar` flag, if
+/// given, overrides the coverage check's export format regardless of which of the two built
+/// the `Config`.
+///
+/// # Returns
+///
+/// `Ok(None)` if neither `--checks` nor `--config` is given, so `main` falls back to the
+/// interactive menu.
+fn parse_ci_config(args: &[String]) -> Result<Option<Config>, Box<dyn Error>> {
+    let mut config = if let Some(checks) = flag_value(args, "--checks") {
+        Some(Config::from_checks_flag(checks))
+    } else if let Some(config_path) = flag_value(args, "--config") {
+        Some(Config::from_file(Path::new(config_path))?)
     } else {
-        download::download_from_git(&target[..])
+        None
+    };
+
+    if let Some(config) = &mut config {
+        if let Some(format) = flag_value(args, "--format") {
+            config.coverage_format = format.to_string();
+        }
+    }
+
+    Ok(config)
+}
+
+/// Returns the value following `flag` in `args`, if present.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|index| args.get(index + 1))
+        .map(String::as_str)
+}
+
+/// Merges several `tarpaulin-report.json` files (e.g. one per feature-flag combination or
+/// target) into one authoritative coverage report, and writes it via `--format
+/// lcov|cobertura|json|html|sonar` (defaulting to `json`).
+///
+/// # Errors
+///
+/// Returns an error if no report paths are given, a report cannot be read or parsed, `--format`
+/// names an unknown reporter, or the merged report cannot be written.
+fn merge_coverage_reports(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let report_paths: Vec<&String> = args
+        .iter()
+        .take_while(|arg| !arg.starts_with("--"))
+        .collect();
+
+    if report_paths.is_empty() {
+        return Err(
+            "Usage: rca --merge-coverage <tarpaulin-report.json>... [--format lcov|cobertura|json|html|sonar]"
+                .into(),
+        );
     }
+
+    let mut reports = Vec::with_capacity(report_paths.len());
+    for report_path in report_paths {
+        let report_json = std::fs::read_to_string(report_path)?;
+        reports.push(quality::coverage_from_tarpaulin_json(&report_json)?);
+    }
+
+    let merged = quality::merge_coverage(reports);
+
+    let format = flag_value(args, "--format").unwrap_or("json");
+    let reporter =
+        coverage::reporter_for(format).ok_or_else(|| format!("unknown format `{format}`"))?;
+    let written_path = reporter.write(&merged, "merged_coverage_report")?;
+
+    println!(
+        "{}",
+        Green.bold().paint(format!("-> Wrote {written_path}"))
+    );
+
+    Ok(())
 }
 
 /// Downloads and installs dependencies if requested by the user.
 ///
 /// This function asks the user if they want to install dependencies, and if the response is 'y', it installs Rust toolchain dependencies.
 ///
+/// `--parallel [N]` anywhere in `args` switches this to
+/// [`dependencies::update_and_install_dependencies_parallel`] instead, installing Rustup
+/// components and Cargo subcommands across a bounded worker pool of `N` (or the available CPU
+/// count if no number follows the flag) instead of one at a time.
+///
 /// # Returns
 ///
 /// `Ok(())` if the installation is successful, or an error if there is an issue with user input or dependency installation.
-fn download_dependencies() -> Result<(), Box<dyn Error>> {
+fn download_dependencies(args: &[String]) -> Result<(), Box<dyn Error>> {
     print!("\nDo you want to install dependencies? (y/n): ");
     std::io::stdout().flush()?;
 
@@ -40,7 +138,13 @@ fn download_dependencies() -> Result<(), Box<dyn Error>> {
         match option.trim() {
             "y" => {
                 println!("{}", Green.bold().paint("Installing Dependencies..."));
-                dependencies::update_and_install_dependencies().unwrap();
+                match parallelism_flag(args) {
+                    Some(parallelism) => {
+                        dependencies::update_and_install_dependencies_parallel(parallelism)
+                            .unwrap();
+                    }
+                    None => dependencies::update_and_install_dependencies().unwrap(),
+                }
                 break;
             }
             "n" => {
@@ -54,6 +158,57 @@ fn download_dependencies() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Returns `Some(parallelism)` if `--parallel` is present in `args`, where `parallelism` is the
+/// number following the flag, or `None` (meaning "default to the available CPU count") if the
+/// flag is bare or not followed by a valid number.
+fn parallelism_flag(args: &[String]) -> Option<Option<usize>> {
+    if !args.iter().any(|arg| arg == "--parallel") {
+        return None;
+    }
+
+    Some(flag_value(args, "--parallel").and_then(|value| value.parse().ok()))
+}
+
+/// Installs and verifies Rustup components across several toolchains (see
+/// [`dependencies::install_and_verify_toolchains`]) and prints a report for each, driven by `rca
+/// --toolchains [stable,nightly,...]`. With no comma-separated list, every currently installed
+/// toolchain is targeted.
+///
+/// # Errors
+///
+/// Returns an error if toolchains cannot be enumerated or a component fails to install on one or
+/// more of them.
+fn report_toolchains(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let toolchains: Option<Vec<String>> = args
+        .first()
+        .filter(|arg| !arg.starts_with("--"))
+        .map(|list| list.split(',').map(str::to_string).collect());
+
+    let manifest = dependencies::DependencyManifest::discover();
+    let reports = dependencies::install_and_verify_toolchains(&manifest, toolchains.as_deref())
+        .map_err(|errors| format!("{errors:?}"))?;
+
+    for report in &reports {
+        println!("{}", Green.bold().paint(format!("-> {}", report.toolchain)));
+        println!(
+            "\trustc: {}",
+            report.rustc_version.as_deref().unwrap_or("unavailable")
+        );
+        println!(
+            "\tcargo: {}",
+            report.cargo_version.as_deref().unwrap_or("unavailable")
+        );
+        for (component, installed) in &report.components {
+            println!(
+                "\t{component}: {}",
+                if *installed { "installed" } else { "missing" }
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Performs actions based on user-selected options.
 ///
 /// This function displays a menu of options for analyzing Rust code and performs the selected action based on the user's input.
@@ -80,7 +235,9 @@ fn do_action(path: &std::ffi::OsStr) -> Result<(), Box<dyn Error>> {
         println!("\t10. Find all issues");
         println!("\t11. Find all quality metrics");
         println!("\t12. Generate Quality Report");
-        println!("\t13. Exit");
+        println!("\t13. Generate Issues Report");
+        println!("\t14. Generate SonarQube Issues Report");
+        println!("\t15. Exit");
 
         print!("\nChoose an option: ");
         std::io::stdout().flush()?;
@@ -89,19 +246,21 @@ fn do_action(path: &std::ffi::OsStr) -> Result<(), Box<dyn Error>> {
         let _ = std::io::stdin().read_line(&mut option)?;
 
         match option.trim() {
-            "1" => issues::find_compilation_errors(&path),
-            "2" => issues::find_formatting_issues(&path),
-            "3" => issues::find_outdated_dependencies(&path),
-            "4" => issues::find_vulnerable_dependencies(&path),
-            "5" => issues::find_integer_arithmetics(&path),
-            "6" => issues::find_unwrap_expect(&path),
-            "7" => quality::search_sloc_number(&path),
-            "8" => quality::search_dependency_graph(&path),
-            "9" => quality::search_code_coverage(&path),
-            "10" => issues::search(&path),
-            "11" => quality::search(&path),
-            "12" => quality::generate_quality_report(&path)?,
-            "13" => break,
+            "1" => print_if_err(issues::find_compilation_errors(&path)),
+            "2" => print_if_err(issues::find_formatting_issues(&path)),
+            "3" => print_if_err(issues::find_outdated_dependencies(&path)),
+            "4" => print_if_err(issues::find_vulnerable_dependencies(&path)),
+            "5" => print_if_err(issues::find_integer_arithmetics(&path)),
+            "6" => print_if_err(issues::find_unwrap_expect(&path)),
+            "7" => print_if_err(quality::search_sloc_number(&path)),
+            "8" => print_if_err(quality::search_dependency_graph(&path)),
+            "9" => print_if_err(quality::search_code_coverage(&path)),
+            "10" => print_if_err(issues::search(&path)),
+            "11" => print_if_err(quality::search(&path)),
+            "12" => print_if_err(quality::generate_quality_report(&path)),
+            "13" => print_if_err(issues::generate_issues_report(&path)),
+            "14" => print_if_err(issues::generate_sonar_issues_report(&path)),
+            "15" => break,
             _ => println!("Invalid option."),
         }
     }
@@ -109,20 +268,67 @@ fn do_action(path: &std::ffi::OsStr) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Prints a menu action's error, if any, instead of propagating it, so one check finding real
+/// issues (a `cargo check`/`cargo fmt --check` failure is the normal case, not an exceptional
+/// one) loops back to the menu rather than killing the whole interactive session.
+fn print_if_err<T, E: std::fmt::Display>(result: Result<T, E>) {
+    if let Err(error) = result {
+        eprintln!("{error}");
+    }
+}
+
 /// Main entry point for the Rust Code Analyzer.
 ///
-/// This function serves as the main entry point for the Rust Code Analyzer. It initiates the download of a Git repository, installs dependencies, and provides a menu for the user to choose actions.
+/// This function serves as the main entry point for the Rust Code Analyzer. Besides its usual
+/// job of downloading a repository and either running CI checks or showing the interactive
+/// menu, `rca --merge-coverage <reports>...` and `rca --toolchains [stable,nightly,...]` take
+/// standalone paths that fold several `tarpaulin-report.json` files into one report, or
+/// install/verify Rustup components across multiple toolchains, without needing a target
+/// repository at all. Dependency installation (interactive or `--checks`/`--config` CI mode) also
+/// takes an optional `--parallel [N]` flag to install across a bounded worker pool instead of one
+/// dependency at a time.
 ///
 /// # Returns
 ///
-/// `Ok(())` if the program runs successfully, or an error if there are issues with downloading, dependency installation, or user actions.
+/// `Ok(())` if the program runs successfully, or an error if there are issues with downloading,
+/// dependency installation, or user actions.
 fn main() -> Result<(), Box<dyn Error>> {
     println!("{}", Green.bold().paint("Welcome to Rust Code Analyzer!\n"));
 
-    let path: std::path::PathBuf = download_repository();
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.first().map(String::as_str) == Some("--merge-coverage") {
+        return merge_coverage_reports(&args[1..]);
+    }
+
+    if args.first().map(String::as_str) == Some("--toolchains") {
+        return report_toolchains(&args[1..]);
+    }
+
+    let target = args.first().ok_or(
+        "Usage: rca <path> [--checks a,b,c | --config rca.toml] [--format lcov|cobertura|json|html|sonar] [--parallel [N]]",
+    )?;
+
+    let path: std::path::PathBuf = download_repository(target)?;
     let path: &std::ffi::OsStr = path.as_os_str();
 
-    download_dependencies()?;
+    if let Some(config) = parse_ci_config(&args)? {
+        if config.install_dependencies {
+            match parallelism_flag(&args) {
+                Some(parallelism) => {
+                    dependencies::update_and_install_dependencies_parallel(parallelism)
+                        .map_err(|errors| format!("{errors:?}"))?;
+                }
+                None => dependencies::update_and_install_dependencies()
+                    .map_err(|errors| format!("{errors:?}"))?,
+            }
+        }
+
+        let blocking_failed = config::run_checks(&config, path);
+        std::process::exit(i32::from(blocking_failed));
+    }
+
+    download_dependencies(&args)?;
 
     do_action(path)?;
 
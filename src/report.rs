@@ -29,6 +29,52 @@ pub struct Sloc {
     pub inaccurate: bool,
 }
 
+/// Whether a branch or condition has been observed taking each of its possible outcomes.
+///
+/// Mirrors Tarpaulin's own `LogicState`, as found in `tarpaulin-report.json`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LogicState {
+    /// Whether this branch/condition was ever observed to evaluate `true`.
+    pub been_true: bool,
+    /// Whether this branch/condition was ever observed to evaluate `false`.
+    pub been_false: bool,
+}
+
+impl LogicState {
+    /// A branch or condition is only fully covered once both outcomes have been observed.
+    pub fn is_covered(&self) -> bool {
+        self.been_true && self.been_false
+    }
+}
+
+/// What Tarpaulin observed for a single instrumented line: a plain hit count for straight-line
+/// code, the outcomes of a two-way branch, or the outcomes of each condition in a compound
+/// boolean expression.
+///
+/// Mirrors Tarpaulin's own `CoverageStat`, as found in `tarpaulin-report.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CoverageStat {
+    /// The number of times this line was executed.
+    Line(usize),
+    /// The outcomes observed for a two-way branch.
+    Branch(LogicState),
+    /// The outcomes observed for each condition of a compound boolean expression.
+    Condition(Vec<LogicState>),
+}
+
+/// A single coverage trace for one instrumented line.
+///
+/// Mirrors Tarpaulin's own `Trace`, as found in `tarpaulin-report.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trace {
+    /// The 1-based line number this trace covers.
+    pub line: usize,
+    /// The function this trace belongs to, if known.
+    pub fn_name: Option<String>,
+    /// What Tarpaulin observed for this line.
+    pub stats: CoverageStat,
+}
+
 /// Information about code coverage for individual source files.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileCoverage {
@@ -36,6 +82,13 @@ pub struct FileCoverage {
     pub name: String,
     /// The lines in the file that are not covered by tests.
     pub uncovered_lines: Vec<u32>,
+    /// The raw per-line traces Tarpaulin recorded for this file, including branch and
+    /// condition coverage.
+    pub traces: Vec<Trace>,
+    /// The file's full source text, as recorded by Tarpaulin, so reporters (e.g. the HTML
+    /// reporter) can render an annotated, line-by-line view without re-reading the file from
+    /// disk.
+    pub content: String,
 }
 
 /// Information about code coverage for a project.
@@ -43,12 +96,20 @@ pub struct FileCoverage {
 pub struct Coverage {
     /// Code coverage data for individual source files.
     pub file_coverage: Vec<FileCoverage>,
-    /// The total code coverage percentage.
+    /// The total line coverage percentage.
     pub total_coverage_percentage: f64,
     /// The total number of covered lines.
     pub num_covered_lines: u32,
     /// The total number of lines in the project.
     pub total_lines: u32,
+    /// The total number of two-way branches Tarpaulin instrumented.
+    pub total_branches: u32,
+    /// The number of those branches where both outcomes were observed.
+    pub num_covered_branches: u32,
+    /// The total number of conditions (in compound boolean expressions) Tarpaulin instrumented.
+    pub total_conditions: u32,
+    /// The number of those conditions where both outcomes were observed.
+    pub num_covered_conditions: u32,
 }
 
 /// Information about remote and local paths.
@@ -68,6 +129,33 @@ pub struct Quality {
     pub coverage: Coverage,
 }
 
+/// A single issue discovered during analysis, normalized across `cargo check`, `cargo clippy`,
+/// `cargo audit`, and `cargo outdated` into one machine-readable shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Issue {
+    /// The kind of issue this record represents.
+    pub kind: crate::issues::IssueKind,
+    /// The file the issue was found in, if known.
+    pub file: Option<String>,
+    /// The 1-based line number the issue was found at, if known.
+    pub line: Option<usize>,
+    /// The 1-based column number the issue was found at, if known.
+    pub column: Option<usize>,
+    /// The lint, advisory, or crate name responsible for the issue.
+    pub name: String,
+    /// The severity as reported by the underlying tool (e.g. "error", "warning").
+    pub severity: String,
+    /// A human-readable description of the issue.
+    pub message: String,
+}
+
+/// A collection of issues found across all checks, ready for JSON (or future SARIF) export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Issues {
+    /// The issues found.
+    pub issues: Vec<Issue>,
+}
+
 /// A struct representing a comprehensive report.
 pub struct Report {
     /// Information about paths (remote and local).
@@ -14,8 +14,15 @@ pub enum TargetPathError {
 pub enum TargetPath {
     /// A local filesystem path.
     Path(PathBuf),
-    /// A remote repository URL.
-    RemoteRepository(String),
+    /// A remote repository URL, optionally pinned to a ref and/or scoped to a subdirectory.
+    RemoteRepository {
+        /// The bare Git URL, with any `#ref:subdir` fragment already stripped off.
+        url: String,
+        /// The tag, branch, or commit to check out, if the URL carried a `#ref` fragment.
+        reference: Option<String>,
+        /// The in-repo subdirectory to analyze, if the URL carried a `#ref:subdir` fragment.
+        subdir: Option<String>,
+    },
 }
 
 impl TargetPath {
@@ -24,6 +31,10 @@ impl TargetPath {
     /// If the input string matches the regular expression for a remote repository URL, it creates a `RemoteRepository`.
     /// If the input string represents an existing local filesystem path, it creates a `Path`.
     ///
+    /// A remote URL may carry a `#reference` or `#reference:subdir` fragment, e.g.
+    /// `https://github.com/user/repo.git#v1.2.0` or `...#main:crates/foo`, to pin the checkout
+    /// to a tag/branch and/or scope analysis to a single subdirectory of the repository.
+    ///
     /// # Arguments
     ///
     /// * `target_path` - The input string representing a path or a remote repository URL.
@@ -45,7 +56,7 @@ impl TargetPath {
     ///     Err(_) => {},
     /// }
     ///
-    /// match TargetPath::new("https://github.com/hknio/rca.git".to_string()) {
+    /// match TargetPath::new("https://github.com/hknio/rca.git#v1.2.0:crates/foo".to_string()) {
     ///     Ok(target_path) => {
     ///         assert!(target_path.is_remote());
     ///     },
@@ -58,7 +69,12 @@ impl TargetPath {
                 .expect("Fatal Error: Cannot create regular expression");
 
         if regex.is_match(&target_path) {
-            Ok(TargetPath::RemoteRepository(target_path))
+            let (url, reference, subdir) = Self::parse_remote_spec(&target_path);
+            Ok(TargetPath::RemoteRepository {
+                url,
+                reference,
+                subdir,
+            })
         } else {
             let path_buffer: PathBuf = PathBuf::from(target_path.clone());
             if path_buffer.as_path().exists() {
@@ -69,6 +85,23 @@ impl TargetPath {
         }
     }
 
+    /// Splits a remote target spec into its bare Git URL and optional `#reference[:subdir]`
+    /// fragment, e.g. `https://host/repo.git#v1.2.0:crates/foo` becomes
+    /// `("https://host/repo.git", Some("v1.2.0"), Some("crates/foo"))`.
+    fn parse_remote_spec(spec: &str) -> (String, Option<String>, Option<String>) {
+        match spec.split_once('#') {
+            Some((url, fragment)) => match fragment.split_once(':') {
+                Some((reference, subdir)) => (
+                    url.to_string(),
+                    Some(reference.to_string()),
+                    Some(subdir.to_string()),
+                ),
+                None => (url.to_string(), Some(fragment.to_string()), None),
+            },
+            None => (spec.to_string(), None, None),
+        }
+    }
+
     /// Checks if the `TargetPath` is a local path.
     ///
     /// # Returns
@@ -78,7 +111,7 @@ impl TargetPath {
     pub fn is_local(&self) -> bool {
         match self {
             TargetPath::Path(_) => true,
-            TargetPath::RemoteRepository(_) => false,
+            TargetPath::RemoteRepository { .. } => false,
         }
     }
 